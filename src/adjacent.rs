@@ -0,0 +1,219 @@
+pub mod hebrew_adjacent {
+    use crate::*;
+
+    /// Checks if the given character is the new sheqel sign (U+20AA).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_new_sheqel_sign;
+    ///
+    /// assert!(is_adj_new_sheqel_sign('₪'));
+    /// assert!(!is_adj_new_sheqel_sign('$'));
+    /// ```
+    pub const fn is_adj_new_sheqel_sign(c: char) -> bool {
+        // U+20AA
+        matches!(c, '\u{20AA}')
+    }
+    /// Checks if the given character is the alef symbol (U+2135).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_alef_symbol;
+    ///
+    /// assert!(is_adj_alef_symbol('ℵ'));
+    /// assert!(!is_adj_alef_symbol('א'));
+    /// ```
+    pub const fn is_adj_alef_symbol(c: char) -> bool {
+        // U+2135
+        matches!(c, '\u{2135}')
+    }
+    /// Checks if the given character is the bet symbol (U+2136).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_bet_symbol;
+    ///
+    /// assert!(is_adj_bet_symbol('ℶ'));
+    /// ```
+    pub const fn is_adj_bet_symbol(c: char) -> bool {
+        // U+2136
+        matches!(c, '\u{2136}')
+    }
+    /// Checks if the given character is the gimel symbol (U+2137).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_gimel_symbol;
+    ///
+    /// assert!(is_adj_gimel_symbol('ℷ'));
+    /// ```
+    pub const fn is_adj_gimel_symbol(c: char) -> bool {
+        // U+2137
+        matches!(c, '\u{2137}')
+    }
+    /// Checks if the given character is the dalet symbol (U+2138).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_dalet_symbol;
+    ///
+    /// assert!(is_adj_dalet_symbol('ℸ'));
+    /// ```
+    pub const fn is_adj_dalet_symbol(c: char) -> bool {
+        // U+2138
+        matches!(c, '\u{2138}')
+    }
+    /// Checks if the given character is one of the Hebrew letterlike symbols (U+2135..U+2138),
+    /// as used in mathematics (e.g. ℵ₀).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_letterlike_symbol;
+    ///
+    /// assert!(is_adj_letterlike_symbol('ℵ'));
+    /// assert!(!is_adj_letterlike_symbol('ℹ'));
+    /// ```
+    pub const fn is_adj_letterlike_symbol(c: char) -> bool {
+        is_adj_alef_symbol(c)
+            || is_adj_bet_symbol(c)
+            || is_adj_gimel_symbol(c)
+            || is_adj_dalet_symbol(c)
+    }
+    /// Checks if the given character is the combining grapheme joiner (U+034F), used to keep
+    /// marks in a non-canonical order (e.g. two vowels under one letter in Biblical text).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_combining_grapheme_joiner;
+    ///
+    /// assert!(is_adj_combining_grapheme_joiner('\u{034F}'));
+    /// ```
+    pub const fn is_adj_combining_grapheme_joiner(c: char) -> bool {
+        // U+034F
+        matches!(c, '\u{034F}')
+    }
+    /// Checks if the given character is the right-to-left mark (U+200F).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_right_to_left_mark;
+    ///
+    /// assert!(is_adj_right_to_left_mark('\u{200F}'));
+    /// assert!(!is_adj_right_to_left_mark('\u{200E}'));
+    /// ```
+    pub const fn is_adj_right_to_left_mark(c: char) -> bool {
+        // U+200F
+        matches!(c, '\u{200F}')
+    }
+    /// Checks if the given character is the dotted circle (U+25CC), the base on which an
+    /// isolated mark is displayed (e.g. in a table of points).
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_adj_dotted_circle;
+    ///
+    /// assert!(is_adj_dotted_circle('◌'));
+    /// ```
+    pub const fn is_adj_dotted_circle(c: char) -> bool {
+        // U+25CC
+        matches!(c, '\u{25CC}')
+    }
+    /// Checks if the given character is used in Hebrew text but lies outside the unicode
+    /// blocks 'Hebrew' and 'Alphabetic Presentation Forms'.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hebrew_adjacent;
+    ///
+    /// assert!(is_hebrew_adjacent('₪'));
+    /// assert!(is_hebrew_adjacent('\u{200F}'));
+    /// assert!(!is_hebrew_adjacent('א'));
+    /// ```
+    pub const fn is_hebrew_adjacent(c: char) -> bool {
+        is_adj_new_sheqel_sign(c)
+            || is_adj_letterlike_symbol(c)
+            || is_adj_combining_grapheme_joiner(c)
+            || is_adj_right_to_left_mark(c)
+            || is_adj_dotted_circle(c)
+    }
+    /// Checks if the given character belongs to the unicode script 'Hebrew' or is one of the
+    /// Hebrew-adjacent characters (see [`is_hebrew_adjacent`]).
+    ///
+    /// Unlike [`is_script_hbr`] this is not a Unicode property; it is meant for deciding
+    /// whether a run of text is Hebrew text.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_script_hbr, is_script_hbr_extended};
+    ///
+    /// assert!(is_script_hbr_extended('א'));
+    /// assert!(is_script_hbr_extended('₪'));
+    /// assert!(!is_script_hbr('₪'));
+    /// assert!(!is_script_hbr_extended('a'));
+    /// ```
+    pub const fn is_script_hbr_extended(c: char) -> bool {
+        is_script_hbr(c) || is_hebrew_adjacent(c)
+    }
+
+    /// The characters for which [`is_hebrew_adjacent`] returns `true`, in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_hebrew_adjacent, HEBREW_ADJACENT_CHARS};
+    ///
+    /// assert!(HEBREW_ADJACENT_CHARS.iter().all(|c| is_hebrew_adjacent(*c)));
+    /// ```
+    pub const HEBREW_ADJACENT_CHARS: [char; 8] = [
+        '\u{034F}', '\u{200F}', '\u{20AA}', '\u{2135}', '\u{2136}', '\u{2137}', '\u{2138}',
+        '\u{25CC}',
+    ];
+
+    /// Returns the Hebrew letter of a letterlike symbol (e.g. ℵ becomes א), `None` for any
+    /// other character.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::letterlike_to_letter;
+    ///
+    /// assert_eq!(letterlike_to_letter('ℵ'), Some('א'));
+    /// assert_eq!(letterlike_to_letter('ℸ'), Some('ד'));
+    /// assert_eq!(letterlike_to_letter('א'), None);
+    /// ```
+    pub const fn letterlike_to_letter(c: char) -> Option<char> {
+        match c {
+            '\u{2135}' => Some('\u{05D0}'),
+            '\u{2136}' => Some('\u{05D1}'),
+            '\u{2137}' => Some('\u{05D2}'),
+            '\u{2138}' => Some('\u{05D3}'),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_hebrew_adjacent() {
+        for c in (0..0x0011_0000).filter_map(char::from_u32) {
+            assert_eq!(
+                is_hebrew_adjacent(c),
+                HEBREW_ADJACENT_CHARS.binary_search(&c).is_ok()
+            );
+            assert_eq!(
+                is_script_hbr_extended(c),
+                is_script_hbr(c) || is_hebrew_adjacent(c)
+            );
+            assert!(!(is_hebrew_adjacent(c) && is_script_hbr(c)));
+            assert_eq!(
+                letterlike_to_letter(c).is_some(),
+                is_adj_letterlike_symbol(c)
+            );
+        }
+        let letters = ['ℵ', 'ℶ', 'ℷ', 'ℸ']
+            .iter()
+            .filter_map(|c| letterlike_to_letter(*c));
+        assert!(letters.eq(['א', 'ב', 'ג', 'ד']));
+    }
+}
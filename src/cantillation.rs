@@ -0,0 +1,1019 @@
+pub mod accent_hierarchy {
+
+    /// The rank of a disjunctive accent within the prose accent system (21 books).
+    ///
+    /// The ranks are ordered from strong to weak, i.e. `Emperor < King < Duke < Count`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum AccentRank {
+        /// silluq, etnahta
+        Emperor,
+        /// segolta, shalshelet, zaqef qatan, zaqef gadol, tipeha
+        King,
+        /// revia, zarqa, pashta, yetiv, tevir
+        Duke,
+        /// geresh, gershayim, pazer, qarney para, telisha gedola, legarmeh
+        Count,
+    }
+
+    /// A disjunctive (pausal) accent of the prose accent system.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Disjunctive {
+        Silluq,
+        Etnahta,
+        Segolta,
+        Shalshelet,
+        ZaqefQatan,
+        ZaqefGadol,
+        Tipeha,
+        Revia,
+        Zarqa,
+        Pashta,
+        Yetiv,
+        Tevir,
+        Geresh,
+        Gershayim,
+        Pazer,
+        QarneyPara,
+        TelishaGedola,
+        Legarmeh,
+    }
+
+    impl Disjunctive {
+        /// Returns the disjunctive accent encoded by the given character.
+        ///
+        /// Silluq and legarmeh are not encoded by an accent character of their own
+        /// (silluq shares U+05BD with meteg, legarmeh is munah followed by paseq),
+        /// so they are never returned.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::Disjunctive;
+        ///
+        /// assert_eq!(Disjunctive::from_char('\u{0591}'), Some(Disjunctive::Etnahta));
+        /// assert_eq!(Disjunctive::from_char('\u{05A3}'), None);
+        /// ```
+        pub fn from_char(c: char) -> Option<Disjunctive> {
+            let accent = match c {
+                '\u{0591}' => Disjunctive::Etnahta,
+                '\u{0592}' => Disjunctive::Segolta,
+                '\u{0593}' => Disjunctive::Shalshelet,
+                '\u{0594}' => Disjunctive::ZaqefQatan,
+                '\u{0595}' => Disjunctive::ZaqefGadol,
+                '\u{0596}' => Disjunctive::Tipeha,
+                '\u{0597}' => Disjunctive::Revia,
+                // zarqa (prose) is encoded either as zarqa or as zinor
+                '\u{0598}' | '\u{05AE}' => Disjunctive::Zarqa,
+                '\u{0599}' => Disjunctive::Pashta,
+                '\u{059A}' => Disjunctive::Yetiv,
+                '\u{059B}' => Disjunctive::Tevir,
+                '\u{059C}' | '\u{059D}' => Disjunctive::Geresh,
+                '\u{059E}' => Disjunctive::Gershayim,
+                '\u{059F}' => Disjunctive::QarneyPara,
+                '\u{05A0}' => Disjunctive::TelishaGedola,
+                '\u{05A1}' => Disjunctive::Pazer,
+                _ => return None,
+            };
+            Some(accent)
+        }
+        /// Returns the rank of the disjunctive accent.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{AccentRank, Disjunctive};
+        ///
+        /// assert_eq!(Disjunctive::Silluq.rank(), AccentRank::Emperor);
+        /// assert_eq!(Disjunctive::Tipeha.rank(), AccentRank::King);
+        /// assert!(Disjunctive::Etnahta.rank() < Disjunctive::Revia.rank());
+        /// ```
+        pub fn rank(self) -> AccentRank {
+            match self {
+                Disjunctive::Silluq | Disjunctive::Etnahta => AccentRank::Emperor,
+                Disjunctive::Segolta
+                | Disjunctive::Shalshelet
+                | Disjunctive::ZaqefQatan
+                | Disjunctive::ZaqefGadol
+                | Disjunctive::Tipeha => AccentRank::King,
+                Disjunctive::Revia
+                | Disjunctive::Zarqa
+                | Disjunctive::Pashta
+                | Disjunctive::Yetiv
+                | Disjunctive::Tevir => AccentRank::Duke,
+                Disjunctive::Geresh
+                | Disjunctive::Gershayim
+                | Disjunctive::Pazer
+                | Disjunctive::QarneyPara
+                | Disjunctive::TelishaGedola
+                | Disjunctive::Legarmeh => AccentRank::Count,
+            }
+        }
+    }
+
+    /// A conjunctive (servant) accent.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Conjunctive {
+        Munah,
+        Mahapakh,
+        Merkha,
+        MerkhaKefula,
+        Darga,
+        Qadma,
+        TelishaQetana,
+        YerahBenYomo,
+        AtnahHafukh,
+        Iluy,
+    }
+
+    impl Conjunctive {
+        /// Returns the conjunctive accent encoded by the given character.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::Conjunctive;
+        ///
+        /// assert_eq!(Conjunctive::from_char('\u{05A5}'), Some(Conjunctive::Merkha));
+        /// assert_eq!(Conjunctive::from_char('\u{0591}'), None);
+        /// ```
+        pub fn from_char(c: char) -> Option<Conjunctive> {
+            let accent = match c {
+                '\u{05A2}' => Conjunctive::AtnahHafukh,
+                '\u{05A3}' => Conjunctive::Munah,
+                '\u{05A4}' => Conjunctive::Mahapakh,
+                '\u{05A5}' => Conjunctive::Merkha,
+                '\u{05A6}' => Conjunctive::MerkhaKefula,
+                '\u{05A7}' => Conjunctive::Darga,
+                '\u{05A8}' => Conjunctive::Qadma,
+                '\u{05A9}' => Conjunctive::TelishaQetana,
+                '\u{05AA}' => Conjunctive::YerahBenYomo,
+                '\u{05AC}' => Conjunctive::Iluy,
+                _ => return None,
+            };
+            Some(accent)
+        }
+    }
+
+    /// The accent that governs a word.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum WordAccent {
+        /// The word ends an accent domain.
+        Disjunctive(Disjunctive),
+        /// The word is joined to the next word.
+        Conjunctive(Conjunctive),
+        /// The word carries an accent of the poetic accent system only (ole, dehi).
+        Poetic,
+    }
+
+    /// Checks if the given character is a HBR disjunctive accent of the prose accent system.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hbr_accent_disjunctive;
+    ///
+    /// assert!(is_hbr_accent_disjunctive('\u{0591}')); // etnahta
+    /// assert!(!is_hbr_accent_disjunctive('\u{05A5}')); // merkha
+    /// ```
+    pub fn is_hbr_accent_disjunctive(c: char) -> bool {
+        // 0591 .. 05A1 + 05AE
+        Disjunctive::from_char(c).is_some()
+    }
+    /// Checks if the given character is a HBR conjunctive accent.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hbr_accent_conjunctive;
+    ///
+    /// assert!(is_hbr_accent_conjunctive('\u{05A5}')); // merkha
+    /// assert!(!is_hbr_accent_conjunctive('\u{0591}')); // etnahta
+    /// ```
+    pub fn is_hbr_accent_conjunctive(c: char) -> bool {
+        // 05A2 .. 05AA + 05AC
+        Conjunctive::from_char(c).is_some()
+    }
+}
+
+pub mod verse_words {
+    use crate::*;
+    use core::ops::Range;
+
+    /// A word (or a chain of words joined by maqaf) of a verse.
+    ///
+    /// Words joined by a maqaf share a single accent and are therefore returned as one unit.
+    /// A free-standing paseq is attached to the preceding word.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct VerseWord<'a> {
+        /// The text of the word, without surrounding whitespace.
+        pub text: &'a str,
+        /// The byte range of the word within the verse.
+        pub range: Range<usize>,
+    }
+
+    impl<'a> VerseWord<'a> {
+        /// Returns `true` if the word is followed by a paseq (U+05C0).
+        pub fn has_paseq(&self) -> bool {
+            self.text.chars().any(is_hbr_punctuation_paseq)
+        }
+        /// Returns the accent that governs the word, if any.
+        ///
+        /// The strongest disjunctive accent of the word takes precedence over its conjunctive
+        /// accents. A munah followed by a paseq is returned as legarmeh. Silluq can not be
+        /// recognised from the word alone; it is carried by the last word of a verse.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{verse_words, Disjunctive, WordAccent};
+        ///
+        /// let mut words = verse_words("לֵ֣ךְ ׀ לְךָ֛");
+        /// let accent = words.next().unwrap().accent();
+        /// assert_eq!(accent, Some(WordAccent::Disjunctive(Disjunctive::Legarmeh)));
+        /// let accent = words.next().unwrap().accent();
+        /// assert_eq!(accent, Some(WordAccent::Disjunctive(Disjunctive::Tevir)));
+        /// ```
+        pub fn accent(&self) -> Option<WordAccent> {
+            let mut strongest: Option<Disjunctive> = None;
+            let mut conjunctive: Option<Conjunctive> = None;
+            let mut poetic = false;
+            for c in self.text.chars() {
+                if let Some(accent) = Disjunctive::from_char(c) {
+                    strongest = match strongest {
+                        Some(current) if current.rank() <= accent.rank() => Some(current),
+                        _ => Some(accent),
+                    };
+                } else if let Some(accent) = Conjunctive::from_char(c) {
+                    conjunctive = conjunctive.or(Some(accent));
+                } else if is_hbr_accent(c) {
+                    poetic = true;
+                }
+            }
+            match (strongest, conjunctive) {
+                (Some(accent), _) => Some(WordAccent::Disjunctive(accent)),
+                _ if poetic => Some(WordAccent::Poetic),
+                // munah followed by paseq
+                (None, Some(Conjunctive::Munah)) if self.has_paseq() => {
+                    Some(WordAccent::Disjunctive(Disjunctive::Legarmeh))
+                }
+                (None, Some(accent)) => Some(WordAccent::Conjunctive(accent)),
+                (None, None) => None,
+            }
+        }
+    }
+
+    /// An iterator over the words of a verse, see [`verse_words`].
+    #[derive(Debug, Clone)]
+    pub struct VerseWords<'a> {
+        verse: &'a str,
+        position: usize,
+    }
+
+    /// Returns an iterator over the accent units (words) of a verse.
+    ///
+    /// Words are separated by whitespace. Tokens without a consonant (e.g. a
+    /// free-standing paseq or sof pasuq) are joined with the preceding word.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::verse_words;
+    ///
+    /// let verse = "אֶת־הָאָ֑רֶץ לֵ֣ךְ ׀ אֶל׃";
+    /// let words: Vec<&str> = verse_words(verse).map(|w| w.text).collect();
+    /// assert_eq!(words, ["אֶת־הָאָ֑רֶץ", "לֵ֣ךְ ׀", "אֶל׃"]);
+    /// ```
+    pub fn verse_words(verse: &str) -> VerseWords<'_> {
+        VerseWords { verse, position: 0 }
+    }
+
+    impl<'a> Iterator for VerseWords<'a> {
+        type Item = VerseWord<'a>;
+
+        fn next(&mut self) -> Option<VerseWord<'a>> {
+            let mut word: Option<Range<usize>> = None;
+            loop {
+                let rest = &self.verse[self.position..];
+                let start = match rest.find(|c: char| !c.is_whitespace()) {
+                    Some(offset) => self.position + offset,
+                    None => break,
+                };
+                let end = self.verse[start..]
+                    .find(char::is_whitespace)
+                    .map_or(self.verse.len(), |offset| start + offset);
+                let token = &self.verse[start..end];
+                let has_consonant = token.chars().any(is_script_hbr_letter);
+                match word {
+                    // a token without a consonant belongs to the preceding word
+                    Some(ref mut range) if !has_consonant => range.end = end,
+                    Some(_) => break,
+                    None => word = Some(start..end),
+                }
+                self.position = end;
+            }
+            word.map(|range| VerseWord {
+                text: &self.verse[range.clone()],
+                range,
+            })
+        }
+    }
+
+    // a (precomposed) letter of the unicode script 'Hebrew'
+    pub(crate) fn is_script_hbr_letter(c: char) -> bool {
+        is_script_hbr_consonant(c)
+            || is_apf_consonant_with_vowel(c)
+            || is_script_hbr_ligature(c)
+            || is_hbr_yod_triangle(c)
+    }
+}
+
+pub mod trope {
+    use crate::*;
+    use core::iter::Peekable;
+    use core::ops::Range;
+
+    /// The reading traditions for which the names of the accents are available.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Tradition {
+        Ashkenazi,
+        Sephardi,
+    }
+
+    /// The kind of a trope group, named after the disjunctive accent that closes it.
+    ///
+    /// A tipeha that is followed by etnahta or silluq, a pashta that is followed by zaqef qatan
+    /// and a zarqa that is followed by segolta are part of the group of that following accent.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TropeKind {
+        SofPasuq,
+        Etnahta,
+        Segol,
+        Shalshelet,
+        ZaqefQatan,
+        ZaqefGadol,
+        Tipeha,
+        Revia,
+        Zarqa,
+        Pashta,
+        Yetiv,
+        Tevir,
+        Geresh,
+        Gershayim,
+        Pazer,
+        QarneyPara,
+        TelishaGedola,
+        Legarmeh,
+        /// Trailing words that are not closed by a disjunctive accent.
+        Incomplete,
+    }
+
+    impl From<Disjunctive> for TropeKind {
+        fn from(accent: Disjunctive) -> TropeKind {
+            match accent {
+                Disjunctive::Silluq => TropeKind::SofPasuq,
+                Disjunctive::Etnahta => TropeKind::Etnahta,
+                Disjunctive::Segolta => TropeKind::Segol,
+                Disjunctive::Shalshelet => TropeKind::Shalshelet,
+                Disjunctive::ZaqefQatan => TropeKind::ZaqefQatan,
+                Disjunctive::ZaqefGadol => TropeKind::ZaqefGadol,
+                Disjunctive::Tipeha => TropeKind::Tipeha,
+                Disjunctive::Revia => TropeKind::Revia,
+                Disjunctive::Zarqa => TropeKind::Zarqa,
+                Disjunctive::Pashta => TropeKind::Pashta,
+                Disjunctive::Yetiv => TropeKind::Yetiv,
+                Disjunctive::Tevir => TropeKind::Tevir,
+                Disjunctive::Geresh => TropeKind::Geresh,
+                Disjunctive::Gershayim => TropeKind::Gershayim,
+                Disjunctive::Pazer => TropeKind::Pazer,
+                Disjunctive::QarneyPara => TropeKind::QarneyPara,
+                Disjunctive::TelishaGedola => TropeKind::TelishaGedola,
+                Disjunctive::Legarmeh => TropeKind::Legarmeh,
+            }
+        }
+    }
+
+    impl TropeKind {
+        /// Returns the name of the trope group within the given tradition.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{Tradition, TropeKind};
+        ///
+        /// assert_eq!(TropeKind::Tipeha.name(Tradition::Ashkenazi), "Tipcha");
+        /// assert_eq!(TropeKind::Tipeha.name(Tradition::Sephardi), "Tarha");
+        /// ```
+        pub fn name(self, tradition: Tradition) -> &'static str {
+            match (self, tradition) {
+                (TropeKind::SofPasuq, Tradition::Ashkenazi) => "Sof Pasuk",
+                (TropeKind::SofPasuq, Tradition::Sephardi) => "Sof Pasuq",
+                (TropeKind::Etnahta, Tradition::Ashkenazi) => "Etnachta",
+                (TropeKind::Etnahta, Tradition::Sephardi) => "Atnah",
+                (TropeKind::Segol, Tradition::Ashkenazi) => "Segol",
+                (TropeKind::Segol, Tradition::Sephardi) => "Segolta",
+                (TropeKind::Shalshelet, _) => "Shalshelet",
+                (TropeKind::ZaqefQatan, Tradition::Ashkenazi) => "Zakef Katon",
+                (TropeKind::ZaqefQatan, Tradition::Sephardi) => "Zaqef Qaton",
+                (TropeKind::ZaqefGadol, Tradition::Ashkenazi) => "Zakef Gadol",
+                (TropeKind::ZaqefGadol, Tradition::Sephardi) => "Zaqef Gadol",
+                (TropeKind::Tipeha, Tradition::Ashkenazi) => "Tipcha",
+                (TropeKind::Tipeha, Tradition::Sephardi) => "Tarha",
+                (TropeKind::Revia, Tradition::Ashkenazi) => "Revii",
+                (TropeKind::Revia, Tradition::Sephardi) => "Revia",
+                (TropeKind::Zarqa, Tradition::Ashkenazi) => "Zarka",
+                (TropeKind::Zarqa, Tradition::Sephardi) => "Zarqa",
+                (TropeKind::Pashta, Tradition::Ashkenazi) => "Pashta",
+                (TropeKind::Pashta, Tradition::Sephardi) => "Qadma",
+                (TropeKind::Yetiv, Tradition::Ashkenazi) => "Yetiv",
+                (TropeKind::Yetiv, Tradition::Sephardi) => "Shofar Yetiv",
+                (TropeKind::Tevir, _) => "Tevir",
+                (TropeKind::Geresh, _) => "Geresh",
+                (TropeKind::Gershayim, Tradition::Ashkenazi) => "Gershayim",
+                (TropeKind::Gershayim, Tradition::Sephardi) => "Shene Gerishin",
+                (TropeKind::Pazer, Tradition::Ashkenazi) => "Pazer",
+                (TropeKind::Pazer, Tradition::Sephardi) => "Pazer Qaton",
+                (TropeKind::QarneyPara, Tradition::Ashkenazi) => "Karnei Farah",
+                (TropeKind::QarneyPara, Tradition::Sephardi) => "Pazer Gadol",
+                (TropeKind::TelishaGedola, Tradition::Ashkenazi) => "Telisha Gedola",
+                (TropeKind::TelishaGedola, Tradition::Sephardi) => "Talsa",
+                (TropeKind::Legarmeh, Tradition::Ashkenazi) => "Munach Legarmeh",
+                (TropeKind::Legarmeh, Tradition::Sephardi) => "Legarmeh",
+                (TropeKind::Incomplete, _) => "",
+            }
+        }
+    }
+
+    impl Conjunctive {
+        /// Returns the name of the conjunctive accent within the given tradition.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{Conjunctive, Tradition};
+        ///
+        /// assert_eq!(Conjunctive::Munah.name(Tradition::Ashkenazi), "Munach");
+        /// assert_eq!(Conjunctive::Munah.name(Tradition::Sephardi), "Shofar Holekh");
+        /// ```
+        pub fn name(self, tradition: Tradition) -> &'static str {
+            match (self, tradition) {
+                (Conjunctive::Munah, Tradition::Ashkenazi) => "Munach",
+                (Conjunctive::Munah, Tradition::Sephardi) => "Shofar Holekh",
+                (Conjunctive::Mahapakh, Tradition::Ashkenazi) => "Mahpach",
+                (Conjunctive::Mahapakh, Tradition::Sephardi) => "Shofar Mehupakh",
+                (Conjunctive::Merkha, Tradition::Ashkenazi) => "Mercha",
+                (Conjunctive::Merkha, Tradition::Sephardi) => "Maarikh",
+                (Conjunctive::MerkhaKefula, Tradition::Ashkenazi) => "Mercha Kefula",
+                (Conjunctive::MerkhaKefula, Tradition::Sephardi) => "Terei Taamei",
+                (Conjunctive::Darga, _) => "Darga",
+                (Conjunctive::Qadma, Tradition::Ashkenazi) => "Kadma",
+                (Conjunctive::Qadma, Tradition::Sephardi) => "Azla",
+                (Conjunctive::TelishaQetana, Tradition::Ashkenazi) => "Telisha Ketana",
+                (Conjunctive::TelishaQetana, Tradition::Sephardi) => "Tirtsa",
+                (Conjunctive::YerahBenYomo, Tradition::Ashkenazi) => "Yerach Ben Yomo",
+                (Conjunctive::YerahBenYomo, Tradition::Sephardi) => "Yerah Ben Yomo",
+                (Conjunctive::AtnahHafukh, Tradition::Ashkenazi) => "Etnach Hafuch",
+                (Conjunctive::AtnahHafukh, Tradition::Sephardi) => "Atnah Hafukh",
+                (Conjunctive::Iluy, _) => "Iluy",
+            }
+        }
+    }
+
+    /// A group of words that is chanted as one traditional trope phrase.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct TropeGroup {
+        /// The kind of the group.
+        pub kind: TropeKind,
+        /// The range of word indices (see [`verse_words`]) covered by the group.
+        pub words: Range<usize>,
+    }
+
+    // words closed by a disjunctive accent (or by the end of the verse)
+    type Segment = (Range<usize>, Option<Disjunctive>);
+
+    /// An iterator over the trope groups of a verse, see [`trope_groups`].
+    #[derive(Debug, Clone)]
+    pub struct TropeGroups<'a> {
+        words: Peekable<VerseWords<'a>>,
+        index: usize,
+        silluq: bool,
+        pending: Option<Segment>,
+    }
+
+    /// Returns an iterator over the trope groups of a cantillated verse.
+    ///
+    /// When the verse ends with a sof pasuq its last word carries silluq.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{trope_groups, TropeKind};
+    ///
+    /// // Genesis 1:1
+    /// let verse = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃";
+    /// let kinds: Vec<TropeKind> = trope_groups(verse).map(|g| g.kind).collect();
+    /// assert_eq!(kinds, [TropeKind::Etnahta, TropeKind::SofPasuq]);
+    ///
+    /// let groups: Vec<_> = trope_groups(verse).map(|g| g.words).collect();
+    /// assert_eq!(groups, [0..3, 3..7]);
+    /// ```
+    pub fn trope_groups(verse: &str) -> TropeGroups<'_> {
+        let verse = verse.trim_end();
+        TropeGroups {
+            words: verse_words(verse).peekable(),
+            index: 0,
+            silluq: verse.ends_with(is_hbr_punctuation_sof_pasuq),
+            pending: None,
+        }
+    }
+
+    impl<'a> TropeGroups<'a> {
+        fn next_segment(&mut self) -> Option<Segment> {
+            let start = self.index;
+            while let Some(word) = self.words.next() {
+                self.index += 1;
+                let accent = match word.accent() {
+                    _ if self.silluq && self.words.peek().is_none() => Some(Disjunctive::Silluq),
+                    Some(WordAccent::Disjunctive(accent)) => Some(accent),
+                    _ => None,
+                };
+                if accent.is_some() {
+                    return Some((start..self.index, accent));
+                }
+            }
+            if start == self.index {
+                None
+            } else {
+                Some((start..self.index, None))
+            }
+        }
+    }
+
+    impl<'a> Iterator for TropeGroups<'a> {
+        type Item = TropeGroup;
+
+        fn next(&mut self) -> Option<TropeGroup> {
+            let (words, accent) = match self.pending.take() {
+                Some(segment) => segment,
+                None => self.next_segment()?,
+            };
+            let accent = match accent {
+                Some(accent) => accent,
+                None => {
+                    return Some(TropeGroup {
+                        kind: TropeKind::Incomplete,
+                        words,
+                    })
+                }
+            };
+            let leads_to: &[Disjunctive] = match accent {
+                Disjunctive::Tipeha => &[Disjunctive::Etnahta, Disjunctive::Silluq],
+                Disjunctive::Pashta => &[Disjunctive::ZaqefQatan],
+                Disjunctive::Zarqa => &[Disjunctive::Segolta],
+                _ => &[],
+            };
+            if !leads_to.is_empty() {
+                match self.next_segment() {
+                    Some((next_words, Some(next_accent))) if leads_to.contains(&next_accent) => {
+                        return Some(TropeGroup {
+                            kind: TropeKind::from(next_accent),
+                            words: words.start..next_words.end,
+                        });
+                    }
+                    next => self.pending = next,
+                }
+            }
+            Some(TropeGroup {
+                kind: TropeKind::from(accent),
+                words,
+            })
+        }
+    }
+}
+
+pub mod meteg {
+    use crate::*;
+
+    /// The function of a meteg sign (U+05BD) within a word.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MetegKind {
+        /// A secondary stress on a full vowel.
+        Meteg,
+        /// The disjunctive accent on the stressed syllable of the last word of a verse.
+        Silluq,
+        /// A meteg on a sheva or hataf vowel, or on a short vowel of a word joined by maqaf.
+        Gaya,
+    }
+
+    /// Classifies the meteg sign at byte `index` of `word`.
+    ///
+    /// `before_sof_pasuq` tells whether the word is the last word of a verse. In that word,
+    /// the last meteg sign (of the last word joined by maqaf) is silluq.
+    ///
+    /// Returns `None` if there is no meteg sign at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{meteg_kind, MetegKind};
+    ///
+    /// let word = "הָאָֽרֶץ";
+    /// let index = word.find('\u{05BD}').unwrap();
+    /// assert_eq!(meteg_kind(word, index, true), Some(MetegKind::Silluq));
+    /// assert_eq!(meteg_kind(word, index, false), Some(MetegKind::Meteg));
+    ///
+    /// let word = "הֲֽלֹא";
+    /// let index = word.find('\u{05BD}').unwrap();
+    /// assert_eq!(meteg_kind(word, index, false), Some(MetegKind::Gaya));
+    /// ```
+    pub fn meteg_kind(word: &str, index: usize, before_sof_pasuq: bool) -> Option<MetegKind> {
+        match word.get(index..).and_then(|rest| rest.chars().next()) {
+            Some(c) if is_hbr_point_meteg(c) => {}
+            _ => return None,
+        }
+        let after = &word[index + '\u{05BD}'.len_utf8()..];
+        let bound_by_maqaf = after.contains(is_hbr_punctuation_maqaf);
+        if before_sof_pasuq && !bound_by_maqaf && !after.contains(is_hbr_point_meteg) {
+            return Some(MetegKind::Silluq);
+        }
+        // the marks of the letter that carries the meteg
+        let start = word[..index]
+            .rfind(|c: char| !is_hbr_combining_mark(c))
+            .unwrap_or(0);
+        let end = after
+            .find(|c: char| !is_hbr_combining_mark(c))
+            .map_or(word.len(), |offset| index + '\u{05BD}'.len_utf8() + offset);
+        let marks = &word[start..end];
+        if marks.contains(is_hbr_point_semi_vowel)
+            || (bound_by_maqaf && marks.contains(is_short_vowel))
+        {
+            Some(MetegKind::Gaya)
+        } else {
+            Some(MetegKind::Meteg)
+        }
+    }
+
+    /// An iterator over the meteg signs of a text, see [`metegs`].
+    #[derive(Debug, Clone)]
+    pub struct Metegs<'a> {
+        words: VerseWords<'a>,
+        current: Option<VerseWord<'a>>,
+        position: usize,
+    }
+
+    /// Returns an iterator over the byte offsets and kinds of all meteg signs of a text.
+    ///
+    /// A word is the last word of a verse if it is followed by a sof pasuq.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{metegs, MetegKind};
+    ///
+    /// let verse = "וַֽיְהִי־אֽוֹר׃";
+    /// let kinds: Vec<MetegKind> = metegs(verse).map(|(_, kind)| kind).collect();
+    /// assert_eq!(kinds, [MetegKind::Gaya, MetegKind::Silluq]);
+    /// ```
+    pub fn metegs(text: &str) -> Metegs<'_> {
+        Metegs {
+            words: verse_words(text),
+            current: None,
+            position: 0,
+        }
+    }
+
+    impl<'a> Iterator for Metegs<'a> {
+        type Item = (usize, MetegKind);
+
+        fn next(&mut self) -> Option<(usize, MetegKind)> {
+            loop {
+                if let Some(word) = &self.current {
+                    if let Some(offset) = word.text[self.position..].find(is_hbr_point_meteg) {
+                        let index = self.position + offset;
+                        self.position = index + '\u{05BD}'.len_utf8();
+                        let last = word.text.contains(is_hbr_punctuation_sof_pasuq);
+                        let kind = meteg_kind(word.text, index, last)?;
+                        return Some((word.range.start + index, kind));
+                    }
+                }
+                self.current = Some(self.words.next()?);
+                self.position = 0;
+            }
+        }
+    }
+
+    // patah, segol, hiriq, qubuts, qamats qatan
+    fn is_short_vowel(c: char) -> bool {
+        is_hbr_point_patah(c)
+            || is_hbr_point_segol(c)
+            || is_hbr_point_hiriq(c)
+            || is_hbr_point_qubuts(c)
+            || is_hbr_point_qamats_qatan(c)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub mod dichotomy {
+    use crate::*;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use core::fmt;
+    use core::ops::Range;
+
+    /// The domain of a disjunctive accent within a verse.
+    ///
+    /// A domain spans a range of words and ends with the word carrying its disjunctive accent.
+    /// A domain that contains a weaker disjunctive accent is divided in two sub-domains: the
+    /// first ends with the dividing accent, the second ends with the accent of this domain.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AccentDomain {
+        /// The disjunctive accent that closes the domain.
+        pub accent: Disjunctive,
+        /// The range of word indices (see [`VerseTree::words`]) covered by the domain.
+        pub words: Range<usize>,
+        /// The two sub-domains, if the domain is divided.
+        pub children: Option<Box<(AccentDomain, AccentDomain)>>,
+    }
+
+    impl AccentDomain {
+        /// Returns `true` if the domain is not divided any further.
+        pub fn is_leaf(&self) -> bool {
+            self.children.is_none()
+        }
+    }
+
+    /// The syntax tree of a verse, as produced by [`parse_verse`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct VerseTree<'a> {
+        /// The words of the verse.
+        pub words: Vec<VerseWord<'a>>,
+        /// The domain of silluq, i.e. the whole verse.
+        pub root: AccentDomain,
+    }
+
+    /// The reasons why a verse can not be parsed.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DichotomyError {
+        /// The verse does not contain any word.
+        EmptyVerse,
+        /// The verse does not end with a sof pasuq (U+05C3).
+        MissingSofPasuq,
+        /// The word (index) does not carry an accent.
+        UnaccentedWord(usize),
+        /// The word (index) carries an accent of the poetic accent system.
+        UnsupportedAccent(usize),
+        /// The word (index) carries an accent that may not appear within the domain of `domain`.
+        RankViolation { word: usize, domain: Disjunctive },
+    }
+
+    impl fmt::Display for DichotomyError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DichotomyError::EmptyVerse => write!(f, "the verse is empty"),
+                DichotomyError::MissingSofPasuq => {
+                    write!(f, "the verse does not end with sof pasuq")
+                }
+                DichotomyError::UnaccentedWord(word) => write!(f, "word {} has no accent", word),
+                DichotomyError::UnsupportedAccent(word) => {
+                    write!(f, "word {} has an accent of the poetic system", word)
+                }
+                DichotomyError::RankViolation { word, domain } => {
+                    write!(f, "word {} may not divide the domain of {:?}", word, domain)
+                }
+            }
+        }
+    }
+
+    /// Parses a cantillated verse into a binary tree of accent domains.
+    ///
+    /// The verse is divided by continuous dichotomy: each domain is split at the
+    /// strongest disjunctive accent it contains (silluq → etnahta → segolta/zaqef/tipeha → ...).
+    /// When that accent occurs more than once, the domain is split at its first occurrence.
+    /// The last word of the verse always carries silluq.
+    ///
+    /// Only the prose accent system is supported.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{parse_verse, Disjunctive};
+    ///
+    /// // Genesis 1:1
+    /// let verse = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃";
+    /// let tree = parse_verse(verse).unwrap();
+    /// assert_eq!(tree.words.len(), 7);
+    ///
+    /// let (first, second) = &**tree.root.children.as_ref().unwrap();
+    /// assert_eq!(first.accent, Disjunctive::Etnahta);
+    /// assert_eq!(first.words, 0..3);
+    /// assert_eq!(second.accent, Disjunctive::Silluq);
+    /// assert_eq!(second.words, 3..7);
+    /// ```
+    pub fn parse_verse(verse: &str) -> Result<VerseTree<'_>, DichotomyError> {
+        let trimmed = verse.trim_end();
+        match trimmed.chars().last() {
+            None => return Err(DichotomyError::EmptyVerse),
+            Some(c) if !is_hbr_punctuation_sof_pasuq(c) => {
+                return Err(DichotomyError::MissingSofPasuq)
+            }
+            Some(_) => {}
+        }
+        let words: Vec<VerseWord<'_>> = verse_words(trimmed).collect();
+        if words.is_empty() {
+            return Err(DichotomyError::EmptyVerse);
+        }
+        let last = words.len() - 1;
+        let mut accents = Vec::with_capacity(words.len());
+        for (index, word) in words.iter().enumerate() {
+            let accent = match word.accent() {
+                _ if index == last => WordAccent::Disjunctive(Disjunctive::Silluq),
+                Some(WordAccent::Poetic) => return Err(DichotomyError::UnsupportedAccent(index)),
+                Some(accent) => accent,
+                None => return Err(DichotomyError::UnaccentedWord(index)),
+            };
+            accents.push(accent);
+        }
+        let root = divide(&accents, 0..words.len(), Disjunctive::Silluq, true)?;
+        Ok(VerseTree { words, root })
+    }
+
+    // divides the domain (closed by `accent`) recursively
+    fn divide(
+        accents: &[WordAccent],
+        words: Range<usize>,
+        accent: Disjunctive,
+        etnahta_allowed: bool,
+    ) -> Result<AccentDomain, DichotomyError> {
+        let mut divider: Option<(usize, Disjunctive)> = None;
+        let body = &accents[words.start..words.end - 1];
+        for (index, word_accent) in (words.start..).zip(body) {
+            if let WordAccent::Disjunctive(candidate) = *word_accent {
+                match divider {
+                    Some((_, current)) if current.rank() <= candidate.rank() => {}
+                    _ => divider = Some((index, candidate)),
+                }
+            }
+        }
+        let (index, divider) = match divider {
+            Some(found) => found,
+            None => {
+                return Ok(AccentDomain {
+                    accent,
+                    words,
+                    children: None,
+                })
+            }
+        };
+        let allowed = divider.rank() > accent.rank()
+            || (etnahta_allowed
+                && accent == Disjunctive::Silluq
+                && divider == Disjunctive::Etnahta);
+        if !allowed {
+            return Err(DichotomyError::RankViolation {
+                word: index,
+                domain: accent,
+            });
+        }
+        let first = divide(accents, words.start..index + 1, divider, false)?;
+        let second = divide(accents, index + 1..words.end, accent, false)?;
+        Ok(AccentDomain {
+            accent,
+            words,
+            children: Some(Box::new((first, second))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_accent_classes() {
+        for c in '\u{0591}'..='\u{05AF}' {
+            if is_hbr_accent(c) {
+                assert!(!(is_hbr_accent_disjunctive(c) && is_hbr_accent_conjunctive(c)));
+            } else {
+                assert!(!is_hbr_accent_disjunctive(c));
+                assert!(!is_hbr_accent_conjunctive(c));
+            }
+        }
+        assert!(!is_hbr_accent_disjunctive('a'));
+        assert!(!is_hbr_accent_conjunctive('a'));
+    }
+
+    #[test]
+    fn test_verse_words() {
+        let verse = "  וַיֹּ֣אמֶר אֱלֹהִ֔ים יְהִ֣י א֑וֹר ׃ ";
+        assert_eq!(verse_words(verse).count(), 4);
+        let first = verse_words(verse).next().unwrap();
+        assert_eq!(&verse[first.range], "וַיֹּ֣אמֶר");
+        let last = verse_words(verse).last().unwrap();
+        assert_eq!(last.text, "א֑וֹר ׃");
+        assert_eq!(verse_words(" ").count(), 0);
+    }
+
+    #[test]
+    fn test_trope_groups() {
+        // Genesis 1:2 (first half)
+        let verse = "וְהָאָ֗רֶץ הָיְתָ֥ה תֹ֙הוּ֙ וָבֹ֔הוּ וְחֹ֖שֶׁךְ עַל־פְּנֵ֣י תְה֑וֹם";
+        let mut groups = trope_groups(verse);
+        let group = groups.next().unwrap();
+        assert_eq!((group.kind, group.words), (TropeKind::Revia, 0..1));
+        let group = groups.next().unwrap();
+        assert_eq!((group.kind, group.words), (TropeKind::ZaqefQatan, 1..4));
+        let group = groups.next().unwrap();
+        assert_eq!((group.kind, group.words), (TropeKind::Etnahta, 4..7));
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_trope_groups_unmerged() {
+        // a tipeha that is not followed by etnahta or silluq stands alone
+        let mut groups = trope_groups("א֖ ב֔ ג֥");
+        assert_eq!(groups.next().unwrap().kind, TropeKind::Tipeha);
+        assert_eq!(groups.next().unwrap().kind, TropeKind::ZaqefQatan);
+        let group = groups.next().unwrap();
+        assert_eq!((group.kind, group.words), (TropeKind::Incomplete, 2..3));
+        assert!(groups.next().is_none());
+
+        let mut groups = trope_groups("א֖ ב֥");
+        assert_eq!(groups.next().unwrap().kind, TropeKind::Tipeha);
+        assert_eq!(groups.next().unwrap().kind, TropeKind::Incomplete);
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_trope_names() {
+        assert_eq!(TropeKind::Pashta.name(Tradition::Sephardi), "Qadma");
+        assert_eq!(Conjunctive::Qadma.name(Tradition::Sephardi), "Azla");
+        assert_eq!(TropeKind::Incomplete.name(Tradition::Ashkenazi), "");
+    }
+
+    #[test]
+    fn test_meteg_kind() {
+        // sheva ga'ya
+        let word = "יְֽהוֹשֻׁעַ";
+        let index = word.find('\u{05BD}').unwrap();
+        assert_eq!(meteg_kind(word, index, false), Some(MetegKind::Gaya));
+        // short vowel before maqaf
+        let word = "וַֽיְהִי־אֽוֹר";
+        let index = word.find('\u{05BD}').unwrap();
+        assert_eq!(meteg_kind(word, index, true), Some(MetegKind::Gaya));
+        let index = word.rfind('\u{05BD}').unwrap();
+        assert_eq!(meteg_kind(word, index, true), Some(MetegKind::Silluq));
+        // no meteg at the index
+        assert_eq!(meteg_kind(word, 0, true), None);
+        assert_eq!(meteg_kind(word, 1, true), None);
+        assert_eq!(meteg_kind(word, 100, true), None);
+    }
+
+    #[test]
+    fn test_metegs() {
+        // Genesis 1:1-2 (excerpt)
+        let text = "הָאָֽרֶץ׃ וְהָאָ֗רֶץ הָיְתָ֥ה תֹ֙הוּ֙ וָבֹ֔הוּ וְחֹ֖שֶׁךְ עַל־פְּנֵ֣י תְה֑וֹם וְר֣וּחַ אֱלֹהִ֔ים מְרַחֶ֖פֶת עַל־פְּנֵ֥י הַמָּֽיִם׃";
+        let mut metegs = metegs(text);
+        let (offset, kind) = metegs.next().unwrap();
+        assert_eq!(kind, MetegKind::Silluq);
+        assert_eq!(&text[offset..offset + 2], "\u{05BD}");
+        assert_eq!(metegs.next().map(|(_, kind)| kind), Some(MetegKind::Silluq));
+        assert!(metegs.next().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_verse() {
+        // Genesis 1:3
+        let verse = "וַיֹּ֥אמֶר אֱלֹהִ֖ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃";
+        let tree = parse_verse(verse).unwrap();
+        let (first, second) = &**tree.root.children.as_ref().unwrap();
+        assert_eq!(first.accent, Disjunctive::Etnahta);
+        assert_eq!(first.words, 0..4);
+        assert!(second.is_leaf());
+        let (left, right) = &**first.children.as_ref().unwrap();
+        assert_eq!(left.accent, Disjunctive::Tipeha);
+        assert_eq!(left.words, 0..2);
+        assert_eq!(right.words, 2..4);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_verse_errors() {
+        assert_eq!(parse_verse(""), Err(DichotomyError::EmptyVerse));
+        assert_eq!(parse_verse("א֑וֹר"), Err(DichotomyError::MissingSofPasuq));
+        assert_eq!(
+            parse_verse("יְהִי אֽוֹר׃"),
+            Err(DichotomyError::UnaccentedWord(0))
+        );
+        assert_eq!(
+            parse_verse("יְהִ֫י אֽוֹר׃"),
+            Err(DichotomyError::UnsupportedAccent(0))
+        );
+        assert_eq!(
+            parse_verse("יְהִ֑י א֑וֹר אֽוֹר׃"),
+            Err(DichotomyError::RankViolation {
+                word: 1,
+                domain: Disjunctive::Silluq
+            })
+        );
+        assert!(parse_verse("יְהִ֗י א֖וֹר אֽוֹר׃").is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_verse_legarmeh() {
+        let verse = "לֵ֣ךְ ׀ אֶל־הָאָ֖רֶץ אֽוֹר׃";
+        let tree = parse_verse(verse).unwrap();
+        let (first, _) = &**tree.root.children.as_ref().unwrap();
+        assert_eq!(first.accent, Disjunctive::Tipeha);
+        let (left, _) = &**first.children.as_ref().unwrap();
+        assert_eq!(left.accent, Disjunctive::Legarmeh);
+        assert_eq!(left.words, 0..1);
+    }
+}
@@ -0,0 +1,276 @@
+pub mod hebrew_char_set {
+    use crate::*;
+    use core::iter::FromIterator;
+    use core::ops::RangeInclusive;
+
+    // the code points covered by the two bitmaps
+    const HBR_START: u32 = 0x0590;
+    const HBR_END: u32 = 0x05FF;
+    const APF_START: u32 = 0xFB1D;
+    const APF_END: u32 = 0xFB4F;
+
+    /// A set of characters of the Hebrew (U+0590..U+05FF) and Alphabetic Presentation Forms
+    /// (U+FB1D..U+FB4F) blocks, stored as a bitmap.
+    ///
+    /// All constructors and set operations are `const fn`, so custom categories can be
+    /// defined as constants. Characters outside the two blocks are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{HebrewCharSet, HebrewCollection};
+    ///
+    /// // consonants except finals plus maqaf
+    /// const LETTERS: HebrewCharSet = HebrewCharSet::from_collection(HebrewCollection::HbrConsonant)
+    ///     .difference(HebrewCharSet::from_collection(HebrewCollection::HbrConsonantFinal))
+    ///     .with('\u{05BE}');
+    ///
+    /// assert!(LETTERS.contains('כ'));
+    /// assert!(!LETTERS.contains('ך'));
+    /// assert!(LETTERS.contains('־'));
+    /// assert_eq!(LETTERS.len(), 23);
+    /// ```
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct HebrewCharSet {
+        hbr: u128,
+        apf: u64,
+    }
+
+    impl HebrewCharSet {
+        /// Returns an empty set.
+        pub const fn new() -> Self {
+            HebrewCharSet { hbr: 0, apf: 0 }
+        }
+        /// Returns the set of the given characters.
+        pub const fn from_chars(chars: &[char]) -> Self {
+            let mut set = HebrewCharSet::new();
+            let mut i = 0;
+            while i < chars.len() {
+                set = set.with(chars[i]);
+                i += 1;
+            }
+            set
+        }
+        /// Returns the set of the characters of the given ranges.
+        pub const fn from_ranges(ranges: &[RangeInclusive<char>]) -> Self {
+            let mut set = HebrewCharSet::new();
+            let mut i = 0;
+            while i < ranges.len() {
+                set = set.with_range(*ranges[i].start(), *ranges[i].end());
+                i += 1;
+            }
+            set
+        }
+        /// Returns the set of the members of a collection.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{HebrewCharSet, HebrewCollection};
+        ///
+        /// // all points except meteg
+        /// const POINTS: HebrewCharSet =
+        ///     HebrewCharSet::from_collection(HebrewCollection::HbrPoint).without('\u{05BD}');
+        /// assert!(POINTS.contains('\u{05B8}'));
+        /// assert!(!POINTS.contains('\u{05BD}'));
+        /// ```
+        pub const fn from_collection(collection: HebrewCollection) -> Self {
+            HebrewCharSet::from_chars(collection.chars())
+        }
+        /// Returns the set of the characters of both blocks for which the predicate returns
+        /// `true`, e.g. one of the `is_hbr_*` functions for a single character.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{is_hbr_point_meteg, HebrewCharSet};
+        ///
+        /// let meteg = HebrewCharSet::from_predicate(is_hbr_point_meteg);
+        /// assert!(meteg.iter().eq(['\u{05BD}']));
+        /// ```
+        pub fn from_predicate(predicate: fn(char) -> bool) -> Self {
+            HebrewCharSet::all()
+                .iter()
+                .filter(|c| predicate(*c))
+                .collect()
+        }
+        /// Returns the set of all characters of both blocks, assigned or not.
+        pub const fn all() -> Self {
+            HebrewCharSet {
+                hbr: u128::MAX >> (128 - (HBR_END - HBR_START + 1)),
+                apf: u64::MAX >> (64 - (APF_END - APF_START + 1)),
+            }
+        }
+        /// Returns the set with the given character added.
+        pub const fn with(self, c: char) -> Self {
+            self.with_range(c, c)
+        }
+        /// Returns the set with the characters `first..=last` added.
+        pub const fn with_range(self, first: char, last: char) -> Self {
+            self.union(HebrewCharSet::range(first as u32, last as u32))
+        }
+        /// Returns the set with the given character removed.
+        pub const fn without(self, c: char) -> Self {
+            self.difference(HebrewCharSet::range(c as u32, c as u32))
+        }
+        /// Returns the characters that are in `self` or in `other`.
+        pub const fn union(self, other: HebrewCharSet) -> Self {
+            HebrewCharSet {
+                hbr: self.hbr | other.hbr,
+                apf: self.apf | other.apf,
+            }
+        }
+        /// Returns the characters that are in both `self` and `other`.
+        pub const fn intersection(self, other: HebrewCharSet) -> Self {
+            HebrewCharSet {
+                hbr: self.hbr & other.hbr,
+                apf: self.apf & other.apf,
+            }
+        }
+        /// Returns the characters that are in `self` but not in `other`.
+        pub const fn difference(self, other: HebrewCharSet) -> Self {
+            HebrewCharSet {
+                hbr: self.hbr & !other.hbr,
+                apf: self.apf & !other.apf,
+            }
+        }
+        /// Returns the characters of both blocks that are not in `self`.
+        pub const fn complement(self) -> Self {
+            HebrewCharSet::all().difference(self)
+        }
+        /// Checks if the given character is in the set.
+        pub const fn contains(&self, c: char) -> bool {
+            let code = c as u32;
+            if HBR_START <= code && code <= HBR_END {
+                self.hbr & (1 << (code - HBR_START)) != 0
+            } else if APF_START <= code && code <= APF_END {
+                self.apf & (1 << (code - APF_START)) != 0
+            } else {
+                false
+            }
+        }
+        /// Returns the number of characters in the set.
+        pub const fn len(&self) -> usize {
+            (self.hbr.count_ones() + self.apf.count_ones()) as usize
+        }
+        /// Returns `true` if the set contains no characters.
+        pub const fn is_empty(&self) -> bool {
+            self.hbr == 0 && self.apf == 0
+        }
+        /// Returns an iterator over the characters of the set, in code point order.
+        pub fn iter(&self) -> HebrewCharSetIter {
+            HebrewCharSetIter { set: *self }
+        }
+
+        // the set of the code points `first..=last` that lie within the blocks
+        const fn range(first: u32, last: u32) -> Self {
+            HebrewCharSet {
+                hbr: bits_u128(first, last, HBR_START, HBR_END),
+                apf: bits_u64(first, last, APF_START, APF_END),
+            }
+        }
+    }
+
+    // the bits of `first..=last` clipped to `start..=end`
+    const fn bits_u128(first: u32, last: u32, start: u32, end: u32) -> u128 {
+        let first = if first < start { start } else { first };
+        let last = if last > end { end } else { last };
+        if first > last {
+            return 0;
+        }
+        (u128::MAX >> (127 - (last - first))) << (first - start)
+    }
+
+    const fn bits_u64(first: u32, last: u32, start: u32, end: u32) -> u64 {
+        let first = if first < start { start } else { first };
+        let last = if last > end { end } else { last };
+        if first > last {
+            return 0;
+        }
+        (u64::MAX >> (63 - (last - first))) << (first - start)
+    }
+
+    impl FromIterator<char> for HebrewCharSet {
+        fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+            iter.into_iter()
+                .fold(HebrewCharSet::new(), HebrewCharSet::with)
+        }
+    }
+
+    impl IntoIterator for HebrewCharSet {
+        type Item = char;
+        type IntoIter = HebrewCharSetIter;
+
+        fn into_iter(self) -> HebrewCharSetIter {
+            HebrewCharSetIter { set: self }
+        }
+    }
+
+    /// An iterator over the characters of a [`HebrewCharSet`].
+    #[derive(Debug, Clone)]
+    pub struct HebrewCharSetIter {
+        set: HebrewCharSet,
+    }
+
+    impl Iterator for HebrewCharSetIter {
+        type Item = char;
+
+        fn next(&mut self) -> Option<char> {
+            let set = &mut self.set;
+            let code = if set.hbr != 0 {
+                let bit = set.hbr.trailing_zeros();
+                set.hbr &= set.hbr - 1;
+                HBR_START + bit
+            } else if set.apf != 0 {
+                let bit = set.apf.trailing_zeros();
+                set.apf &= set.apf - 1;
+                APF_START + bit
+            } else {
+                return None;
+            };
+            char::from_u32(code)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.set.len(), Some(self.set.len()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_char_set() {
+        for collection in HebrewCollection::ALL.iter().copied() {
+            let set = HebrewCharSet::from_collection(collection);
+            assert!(set.iter().eq(collection.chars().iter().copied()));
+            assert_eq!(HebrewCharSet::from_ranges(collection.ranges()), set);
+            assert_eq!(HebrewCharSet::from_predicate(collection.predicate()), set);
+            assert_eq!(set.len(), collection.chars().len());
+        }
+        let all = HebrewCharSet::all();
+        assert_eq!(all.len(), 112 + 51);
+        assert!(all.contains('\u{0590}') && all.contains('\u{05FF}'));
+        assert!(all.contains('\u{FB1D}') && all.contains('\u{FB4F}'));
+        assert!(!all.contains('\u{058F}') && !all.contains('\u{0600}') && !all.contains('a'));
+        assert!(HebrewCharSet::new().is_empty());
+        assert!(HebrewCharSet::new().with('a').is_empty());
+        assert_eq!(
+            HebrewCharSet::new()
+                .with_range('\u{0500}', '\u{0592}')
+                .len(),
+            3
+        );
+        assert_eq!(all.complement(), HebrewCharSet::new());
+
+        let points = HebrewCharSet::from_collection(HebrewCollection::HbrPoint);
+        let vowels = HebrewCharSet::from_collection(HebrewCollection::HbrPointVowel);
+        assert_eq!(points.intersection(vowels), vowels);
+        assert_eq!(points.difference(vowels).union(vowels), points);
+        assert!(!points.without('\u{05BD}').contains('\u{05BD}'));
+        assert!("אב"
+            .chars()
+            .collect::<HebrewCharSet>()
+            .iter()
+            .eq(['א', 'ב']));
+    }
+}
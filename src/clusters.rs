@@ -0,0 +1,270 @@
+pub mod apf_decomposition {
+    // the consonants with dagesh (U+FB30 .. U+FB4A), indexed by (c - U+FB30)
+    const DAGESH: [Option<char>; 27] = [
+        Some('\u{05D0}'),
+        Some('\u{05D1}'),
+        Some('\u{05D2}'),
+        Some('\u{05D3}'),
+        Some('\u{05D4}'),
+        Some('\u{05D5}'),
+        Some('\u{05D6}'),
+        None,
+        Some('\u{05D8}'),
+        Some('\u{05D9}'),
+        Some('\u{05DA}'),
+        Some('\u{05DB}'),
+        Some('\u{05DC}'),
+        None,
+        Some('\u{05DE}'),
+        None,
+        Some('\u{05E0}'),
+        Some('\u{05E1}'),
+        None,
+        Some('\u{05E3}'),
+        Some('\u{05E4}'),
+        None,
+        Some('\u{05E6}'),
+        Some('\u{05E7}'),
+        Some('\u{05E8}'),
+        Some('\u{05E9}'),
+        Some('\u{05EA}'),
+    ];
+
+    /// Decomposes an APF character into a HBR base character and the HBR points it implies.
+    ///
+    /// The consonants with a vowel or dagesh are decomposed canonically. The wide and
+    /// alternative consonants and the Yiddish ligature are decomposed to their HBR counterpart.
+    /// Returns `None` for characters without a HBR counterpart.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::decompose_apf;
+    ///
+    /// assert_eq!(decompose_apf('\u{FB2C}'), Some(('ש', &['\u{05BC}', '\u{05C1}'][..])));
+    /// assert_eq!(decompose_apf('\u{FB35}'), Some(('ו', &['\u{05BC}'][..])));
+    /// assert_eq!(decompose_apf('\u{FB21}'), Some(('א', &[][..])));
+    /// assert_eq!(decompose_apf('\u{FB4F}'), None);
+    /// assert_eq!(decompose_apf('א'), None);
+    /// ```
+    pub fn decompose_apf(c: char) -> Option<(char, &'static [char])> {
+        let decomposition: (char, &'static [char]) = match c {
+            '\u{FB1D}' => ('\u{05D9}', &['\u{05B4}']),
+            '\u{FB1F}' => ('\u{05F2}', &['\u{05B7}']),
+            '\u{FB20}' => ('\u{05E2}', &[]),
+            '\u{FB21}' => ('\u{05D0}', &[]),
+            '\u{FB22}' => ('\u{05D3}', &[]),
+            '\u{FB23}' => ('\u{05D4}', &[]),
+            '\u{FB24}' => ('\u{05DB}', &[]),
+            '\u{FB25}' => ('\u{05DC}', &[]),
+            '\u{FB26}' => ('\u{05DD}', &[]),
+            '\u{FB27}' => ('\u{05E8}', &[]),
+            '\u{FB28}' => ('\u{05EA}', &[]),
+            '\u{FB2A}' => ('\u{05E9}', &['\u{05C1}']),
+            '\u{FB2B}' => ('\u{05E9}', &['\u{05C2}']),
+            '\u{FB2C}' => ('\u{05E9}', &['\u{05BC}', '\u{05C1}']),
+            '\u{FB2D}' => ('\u{05E9}', &['\u{05BC}', '\u{05C2}']),
+            '\u{FB2E}' => ('\u{05D0}', &['\u{05B7}']),
+            '\u{FB2F}' => ('\u{05D0}', &['\u{05B8}']),
+            '\u{FB30}'..='\u{FB4A}' => {
+                let base = DAGESH[(c as u32 - 0xFB30) as usize]?;
+                (base, &['\u{05BC}'])
+            }
+            '\u{FB4B}' => ('\u{05D5}', &['\u{05B9}']),
+            '\u{FB4C}' => ('\u{05D1}', &['\u{05BF}']),
+            '\u{FB4D}' => ('\u{05DB}', &['\u{05BF}']),
+            '\u{FB4E}' => ('\u{05E4}', &['\u{05BF}']),
+            _ => return None,
+        };
+        Some(decomposition)
+    }
+}
+
+pub mod letter_clusters {
+    use crate::*;
+    use core::iter::{Chain, Copied, Skip};
+    use core::ops::Range;
+    use core::slice;
+    use core::str::Chars;
+
+    /// A base character together with the marks (points, accents) that follow it.
+    ///
+    /// APF characters are decomposed: [`Cluster::base`] holds the HBR base character and
+    /// [`Cluster::marks`] starts with the points implied by the APF character.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Cluster<'a> {
+        /// The text of the cluster.
+        pub text: &'a str,
+        /// The byte range of the cluster within the text.
+        pub range: Range<usize>,
+        /// The (decomposed) base character, `None` if the cluster consists of marks only.
+        pub base: Option<char>,
+        implied: &'static [char],
+    }
+
+    /// An iterator over the marks of a cluster, see [`Cluster::marks`].
+    pub type Marks<'a> = Chain<Copied<slice::Iter<'static, char>>, Skip<Chars<'a>>>;
+
+    impl<'a> Cluster<'a> {
+        /// Returns an iterator over the marks of the cluster, including the points implied by
+        /// a precomposed APF base character.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::clusters;
+        ///
+        /// let cluster = clusters("\u{FB2A}\u{05B8}").next().unwrap();
+        /// assert_eq!(cluster.base, Some('ש'));
+        /// assert!(cluster.marks().eq(['\u{05C1}', '\u{05B8}']));
+        /// ```
+        pub fn marks(&self) -> Marks<'a> {
+            let skip = if self.base.is_some() { 1 } else { 0 };
+            self.implied
+                .iter()
+                .copied()
+                .chain(self.text.chars().skip(skip))
+        }
+        /// Returns `true` if one of the marks of the cluster matches the predicate.
+        pub fn has_mark(&self, predicate: fn(char) -> bool) -> bool {
+            self.marks().any(predicate)
+        }
+        /// Returns the consonant of the cluster, `None` if the base is not a HBR consonant.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::clusters;
+        ///
+        /// let mut clusters = clusters("\u{FB35}־");
+        /// assert_eq!(clusters.next().unwrap().consonant(), Some('ו'));
+        /// assert_eq!(clusters.next().unwrap().consonant(), None);
+        /// ```
+        pub fn consonant(&self) -> Option<char> {
+            self.base.filter(|c| is_hbr_consonant(*c))
+        }
+        /// Returns the first vowel (full vowel or semi-vowel) of the cluster.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::clusters;
+        ///
+        /// let cluster = clusters("בְּ").next().unwrap();
+        /// assert_eq!(cluster.vowel(), Some('\u{05B0}'));
+        /// ```
+        pub fn vowel(&self) -> Option<char> {
+            self.marks()
+                .find(|c| is_hbr_point_vowel(*c) || is_hbr_point_semi_vowel(*c))
+        }
+    }
+
+    /// An iterator over the clusters of a text, see [`clusters`].
+    #[derive(Debug, Clone)]
+    pub struct Clusters<'a> {
+        text: &'a str,
+        position: usize,
+    }
+
+    /// Returns an iterator over the clusters of a text.
+    ///
+    /// Every character that is not a mark starts a new cluster. Marks at the start of the
+    /// text or after a mark-only cluster are returned as a cluster without base.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::clusters;
+    ///
+    /// let texts: Vec<&str> = clusters("שָׁלוֹם").map(|c| c.text).collect();
+    /// assert_eq!(texts, ["שָׁ", "ל", "וֹ", "ם"]);
+    /// ```
+    pub fn clusters(text: &str) -> Clusters<'_> {
+        Clusters { text, position: 0 }
+    }
+
+    impl<'a> Iterator for Clusters<'a> {
+        type Item = Cluster<'a>;
+
+        fn next(&mut self) -> Option<Cluster<'a>> {
+            let rest = &self.text[self.position..];
+            let first = rest.chars().next()?;
+            let (base, implied) = if is_hbr_combining_mark(first) {
+                (None, &[][..])
+            } else {
+                match decompose_apf(first) {
+                    Some((base, implied)) => (Some(base), implied),
+                    None => (Some(first), &[][..]),
+                }
+            };
+            let skip = if base.is_some() { first.len_utf8() } else { 0 };
+            let length = rest[skip..]
+                .find(|c: char| !is_hbr_combining_mark(c))
+                .map_or(rest.len(), |offset| skip + offset);
+            let start = self.position;
+            self.position += length;
+            Some(Cluster {
+                text: &rest[..length],
+                range: start..self.position,
+                base,
+                implied,
+            })
+        }
+    }
+
+    /// Checks if the given character is a mark that combines with the preceding letter.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hbr_combining_mark;
+    ///
+    /// assert!(is_hbr_combining_mark('\u{05B8}')); // qamats
+    /// assert!(is_hbr_combining_mark('\u{0591}')); // etnahta
+    /// assert!(!is_hbr_combining_mark('\u{05BE}')); // maqaf
+    /// ```
+    pub fn is_hbr_combining_mark(c: char) -> bool {
+        is_script_hbr_point(c) || is_hbr_accent(c) || is_hbr_mark(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_decompose_apf() {
+        for c in '\u{FB1D}'..='\u{FB4F}' {
+            match decompose_apf(c) {
+                Some((base, implied)) => {
+                    assert!(is_script_hbr(c));
+                    assert!(is_hbr_block(base));
+                    assert!(implied.iter().all(|c| is_hbr_point(*c)));
+                }
+                None => assert!(matches!(
+                    c,
+                    '\u{FB1E}'
+                        | '\u{FB29}'
+                        | '\u{FB37}'
+                        | '\u{FB3D}'
+                        | '\u{FB3F}'
+                        | '\u{FB42}'
+                        | '\u{FB45}'
+                        | '\u{FB4F}'
+                )),
+            }
+        }
+        assert_eq!(
+            decompose_apf('\u{FB4A}'),
+            Some(('\u{05EA}', &['\u{05BC}'][..]))
+        );
+    }
+
+    #[test]
+    fn test_clusters() {
+        let mut iter = clusters("\u{05B8}בָּ ");
+        let cluster = iter.next().unwrap();
+        assert_eq!((cluster.base, cluster.range), (None, 0..2));
+        let cluster = iter.next().unwrap();
+        assert_eq!((cluster.base, cluster.range.clone()), (Some('ב'), 2..8));
+        assert!(cluster.has_mark(is_hbr_point_dagesh_or_mapiq));
+        assert_eq!(cluster.vowel(), Some('\u{05B8}'));
+        let cluster = iter.next().unwrap();
+        assert_eq!((cluster.base, cluster.consonant()), (Some(' '), None));
+        assert!(iter.next().is_none());
+    }
+}
@@ -0,0 +1,259 @@
+pub mod hebrew_collation {
+    use crate::*;
+    use core::cmp::Ordering;
+    use core::iter;
+
+    // the separator of the levels in a collation key
+    #[cfg(feature = "alloc")]
+    const LEVEL_SEPARATOR: u32 = 0;
+    // the separator of the letters within the secondary and tertiary level
+    const LETTER_SEPARATOR: u32 = 1;
+
+    // the tertiary weights of the forms of a letter
+    const FORM_NORMAL: u32 = 2;
+    const FORM_FINAL: u32 = 3;
+    const FORM_PRESENTATION: u32 = 4;
+
+    /// Compares two texts in the order of a printed Hebrew dictionary.
+    ///
+    /// The texts are compared at three levels; a level is only used if the previous levels
+    /// are equal:
+    ///
+    /// 1. primary: the letters in alphabetical order, a final letter is equal to its medial
+    ///    form and a ligature is equal to its letters;
+    /// 2. secondary: the points of each letter, unpointed before pointed, in the order sheva,
+    ///    hataf patah, patah, qamats, hataf segol, segol, tsere, hiriq, hataf qamats, qamats
+    ///    qatan, holam, holam haser for vav, qubuts, dagesh, shin dot, sin dot, rafe, meteg and
+    ///    varika;
+    /// 3. tertiary: the form of each letter (normal, final, presentation form or ligature) and
+    ///    its accents and marks.
+    ///
+    /// Texts that are equal at all levels are ordered by code point, so the order is total.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::cmp_hebrew;
+    ///
+    /// let mut words = ["מלך", "מלכה", "מַלְכָּה", "מֶלֶךְ", "מלא"];
+    /// words.sort_by(|a, b| cmp_hebrew(a, b));
+    /// assert_eq!(words, ["מלא", "מלך", "מֶלֶךְ", "מלכה", "מַלְכָּה"]);
+    /// ```
+    pub fn cmp_hebrew(a: &str, b: &str) -> Ordering {
+        primary(a)
+            .cmp(primary(b))
+            .then_with(|| secondary(a).cmp(secondary(b)))
+            .then_with(|| tertiary(a).cmp(tertiary(b)))
+            .then_with(|| a.cmp(b))
+    }
+
+    /// Returns the collation key of a text: the keys of two texts compare like
+    /// [`cmp_hebrew`] compares the texts, so texts can be sorted by a key that is computed
+    /// once.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::collation_key;
+    ///
+    /// let mut words = vec!["תורה", "אב", "בית", "אֵם"];
+    /// words.sort_by_cached_key(|word| collation_key(word));
+    /// assert_eq!(words, ["אב", "אֵם", "בית", "תורה"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn collation_key(s: &str) -> alloc::vec::Vec<u32> {
+        primary(s)
+            .chain(iter::once(LEVEL_SEPARATOR))
+            .chain(secondary(s))
+            .chain(iter::once(LEVEL_SEPARATOR))
+            .chain(tertiary(s))
+            .chain(iter::once(LEVEL_SEPARATOR))
+            .chain(s.chars().map(|c| c as u32))
+            .collect()
+    }
+
+    fn primary(s: &str) -> impl Iterator<Item = u32> + '_ {
+        clusters(s).flat_map(|cluster| {
+            let (letters, len) = match cluster.base {
+                None => (['\0'; 2], 0),
+                Some(c) if is_adj_combining_grapheme_joiner(c) => (['\0'; 2], 0),
+                Some('\u{FB4F}') => (['\u{05D0}', '\u{05DC}'], 2),
+                Some('\u{05F0}') => (['\u{05D5}', '\u{05D5}'], 2),
+                Some('\u{05F1}') => (['\u{05D5}', '\u{05D9}'], 2),
+                Some('\u{05F2}') => (['\u{05D9}', '\u{05D9}'], 2),
+                Some(c) => ([medial(c), '\0'], 1),
+            };
+            // the weights of the level are not 0, the level separator
+            letters.into_iter().take(len).map(|c| c as u32 + 1)
+        })
+    }
+
+    fn secondary(s: &str) -> impl Iterator<Item = u32> + '_ {
+        clusters(s).flat_map(|cluster| {
+            SortedWeights::new(cluster, point_weight).chain(iter::once(LETTER_SEPARATOR))
+        })
+    }
+
+    fn tertiary(s: &str) -> impl Iterator<Item = u32> + '_ {
+        clusters(s).flat_map(|cluster| {
+            let form = form_weight(&cluster);
+            iter::once(form)
+                .chain(SortedWeights::new(cluster, mark_weight))
+                .chain(iter::once(LETTER_SEPARATOR))
+        })
+    }
+
+    // the secondary weight of a point
+    fn point_weight(c: char) -> Option<u32> {
+        let weight = match c {
+            '\u{05B0}' => 2,  // sheva
+            '\u{05B2}' => 3,  // hataf patah
+            '\u{05B7}' => 4,  // patah
+            '\u{05B8}' => 5,  // qamats
+            '\u{05B1}' => 6,  // hataf segol
+            '\u{05B6}' => 7,  // segol
+            '\u{05B5}' => 8,  // tsere
+            '\u{05B4}' => 9,  // hiriq
+            '\u{05B3}' => 10, // hataf qamats
+            '\u{05C7}' => 11, // qamats qatan
+            '\u{05B9}' => 12, // holam
+            '\u{05BA}' => 13, // holam haser for vav
+            '\u{05BB}' => 14, // qubuts
+            '\u{05BC}' => 15, // dagesh or mapiq
+            '\u{05C1}' => 16, // shin dot
+            '\u{05C2}' => 17, // sin dot
+            '\u{05BF}' => 18, // rafe
+            '\u{05BD}' => 19, // meteg
+            '\u{FB1E}' => 20, // varika
+            _ => return None,
+        };
+        Some(weight)
+    }
+
+    // the tertiary weight of an accent or mark
+    fn mark_weight(c: char) -> Option<u32> {
+        if point_weight(c).is_some() {
+            None
+        } else {
+            Some(c as u32)
+        }
+    }
+
+    fn form_weight(cluster: &Cluster) -> u32 {
+        match cluster.text.chars().next() {
+            Some(first) if is_apf_block(first) || is_hbr_ligature_yiddish(first) => {
+                FORM_PRESENTATION
+            }
+            Some(first) if is_hbr_consonant_final(first) => FORM_FINAL,
+            _ => FORM_NORMAL,
+        }
+    }
+
+    // the weights of the marks of a cluster in increasing order, without allocating
+    struct SortedWeights<'a> {
+        cluster: Cluster<'a>,
+        weight: fn(char) -> Option<u32>,
+        // the last weight returned and how often it was returned
+        current: u32,
+        returned: usize,
+    }
+
+    impl<'a> SortedWeights<'a> {
+        fn new(cluster: Cluster<'a>, weight: fn(char) -> Option<u32>) -> Self {
+            SortedWeights {
+                cluster,
+                weight,
+                current: 0,
+                returned: 0,
+            }
+        }
+    }
+
+    impl<'a> Iterator for SortedWeights<'a> {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            let current = self.current;
+            let weights = self.cluster.marks().filter_map(self.weight);
+            if weights.filter(|weight| *weight == current).count() > self.returned {
+                self.returned += 1;
+                return Some(current);
+            }
+            let next = self
+                .cluster
+                .marks()
+                .filter_map(self.weight)
+                .filter(|weight| *weight > current)
+                .min()?;
+            self.current = next;
+            self.returned = 1;
+            Some(next)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_cmp_hebrew() {
+        // primary: letters, finals equal to medials
+        assert_eq!(cmp_hebrew("אב", "בא"), Ordering::Less);
+        assert_eq!(cmp_hebrew("ך", "כא"), Ordering::Less);
+        assert_eq!(cmp_hebrew("ת", "כ"), Ordering::Greater);
+        assert_eq!(cmp_hebrew("\u{FB4F}", "אם"), Ordering::Less);
+        // secondary: unpointed first, then the order of the points
+        assert_eq!(cmp_hebrew("בר", "בַר"), Ordering::Less);
+        assert_eq!(cmp_hebrew("בַר", "בָר"), Ordering::Less);
+        assert_eq!(cmp_hebrew("בְר", "בַר"), Ordering::Less);
+        assert_eq!(cmp_hebrew("שׁ", "שׂ"), Ordering::Less);
+        // the order of the marks of a letter does not matter
+        let (a, b) = ("ב\u{05BC}\u{05B8}", "ב\u{05B8}\u{05BC}");
+        assert_eq!(cmp_hebrew(a, b), a.cmp(b));
+        assert_eq!(cmp_hebrew("שָׁ", "שָׁ"), Ordering::Equal);
+        // tertiary: forms and accents
+        assert_eq!(cmp_hebrew("כ", "ך"), Ordering::Less);
+        assert_eq!(cmp_hebrew("אַ", "\u{FB2E}"), Ordering::Less);
+        assert_eq!(cmp_hebrew("אַ", "אַ֣"), Ordering::Less);
+        assert_eq!(cmp_hebrew("\u{FB2E}", "אָ"), Ordering::Less);
+        // identical: code points
+        assert_eq!(cmp_hebrew(a, b), Ordering::Greater);
+        assert_eq!(cmp_hebrew("", "א"), Ordering::Less);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_collation_key() {
+        let words = [
+            "",
+            "א",
+            "אב",
+            "אָב",
+            "אַב",
+            "\u{FB2E}ב",
+            "אָב֑",
+            "ב",
+            "בּ",
+            "ך",
+            "כא",
+            "\u{FB4F}",
+            "אם",
+            "שׁ",
+            "שׂ",
+            "\u{FB2A}",
+            "a",
+            " ",
+        ];
+        for a in words.iter() {
+            for b in words.iter() {
+                assert_eq!(
+                    collation_key(a).cmp(&collation_key(b)),
+                    cmp_hebrew(a, b),
+                    "{} {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod unicode_script_hebrew {
     use crate::*;
+    use core::ops::RangeInclusive;
     /// Checks if the given character belongs to the unicode script 'Hebrew'.
     ///
     /// # Example
@@ -12,8 +13,8 @@ pub mod unicode_script_hebrew {
     /// let non_hbr_ch = 'д';
     /// assert!(!is_script_hbr(non_hbr_ch));
     /// ```
-    pub fn is_script_hbr(c: char) -> bool {
-        is_hbr_block(c) || is_apf_block(c)
+    pub const fn is_script_hbr(c: char) -> bool {
+        in_ranges(c, SCRIPT_HBR_RANGES)
     }
     /// Checks if the given character is a 'consonant' type within the unicode script 'Hebrew'.
     ///
@@ -30,8 +31,8 @@ pub mod unicode_script_hebrew {
     /// assert!(is_script_hbr_consonant(afp_alternative));
     ///
     /// ```
-    pub fn is_script_hbr_consonant(c: char) -> bool {
-        is_hbr_consonant(c) || is_apf_consonant(c)
+    pub const fn is_script_hbr_consonant(c: char) -> bool {
+        in_ranges(c, SCRIPT_HBR_CONSONANT_RANGES)
     }
     /// Checks if the given character is a 'point' type within the unicode script 'Hebrew'.
     ///
@@ -53,8 +54,8 @@ pub mod unicode_script_hebrew {
     /// let reading_sign = '\u{FB1E}';
     /// assert!(is_script_hbr_point(reading_sign));
     /// ```
-    pub fn is_script_hbr_point(c: char) -> bool {
-        is_hbr_point(c) || is_apf_point_reading_sign(c)
+    pub const fn is_script_hbr_point(c: char) -> bool {
+        in_ranges(c, SCRIPT_HBR_POINT_RANGES)
     }
     /// Checks if the given character is a 'point' type within the unicode script 'Hebrew'.
     ///
@@ -76,7 +77,7 @@ pub mod unicode_script_hebrew {
     /// let reading_sign = '\u{FB1E}';
     /// assert!(is_script_hbr_point_reading_sign(reading_sign));
     /// ```
-    pub fn is_script_hbr_point_reading_sign(c: char) -> bool {
+    pub const fn is_script_hbr_point_reading_sign(c: char) -> bool {
         is_hbr_point_reading_sign(c) || is_apf_point_reading_sign(c)
     }
 
@@ -94,8 +95,8 @@ pub mod unicode_script_hebrew {
     /// let liga_yiddish = '\u{FB1F}';
     /// assert!(is_script_hbr_ligature(liga_yiddish));
     /// ```
-    pub fn is_script_hbr_ligature(c: char) -> bool {
-        is_hbr_ligature_yiddish(c) || is_apf_ligature(c)
+    pub const fn is_script_hbr_ligature(c: char) -> bool {
+        in_ranges(c, SCRIPT_HBR_LIGATURE_RANGES)
     }
 
     /// Checks if the given character is a 'ligature_yiddisch' type within the unicode script 'Hebrew'.
@@ -112,13 +113,27 @@ pub mod unicode_script_hebrew {
     /// let liga_yiddish = '\u{FB1F}';
     /// assert!(is_script_hbr_ligature_yiddisch(liga_yiddish));
     /// ```
-    pub fn is_script_hbr_ligature_yiddisch(c: char) -> bool {
-        is_hbr_ligature_yiddish(c) || is_apf_ligature_yiddisch_yod_yod_patah(c)
+    pub const fn is_script_hbr_ligature_yiddisch(c: char) -> bool {
+        in_ranges(c, SCRIPT_HBR_LIGATURE_YIDDISCH_RANGES)
     }
+
+    /// The characters for which [`is_script_hbr_point_reading_sign`] returns `true`, in code point order.
+    pub const SCRIPT_HBR_POINT_READING_SIGN_CHARS: [char; 6] = [
+        '\u{05BC}', '\u{05BD}', '\u{05BF}', '\u{05C1}', '\u{05C2}', '\u{FB1E}',
+    ];
+
+    /// The characters for which [`is_script_hbr_point_reading_sign`] returns `true`, as ranges in code point order.
+    pub const SCRIPT_HBR_POINT_READING_SIGN_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05BC}'..='\u{05BD}',
+        '\u{05BF}'..='\u{05BF}',
+        '\u{05C1}'..='\u{05C2}',
+        '\u{FB1E}'..='\u{FB1E}',
+    ];
 }
 
 pub mod unicode_block_hebrew {
     use crate::*;
+    use core::ops::RangeInclusive;
     /// Checks if the given character belongs to the unicode block 'Hebrew' (HBR)
     ///
     /// # Examples
@@ -130,14 +145,8 @@ pub mod unicode_block_hebrew {
     ///    assert!(is_hbr_block(c));
     /// }
     /// ```
-    pub fn is_hbr_block(c: char) -> bool {
-        is_hbr_accent(c)
-            || is_hbr_mark(c)
-            || is_hbr_point(c)
-            || is_hbr_punctuation(c)
-            || is_hbr_consonant(c)
-            || is_hbr_yod_triangle(c)
-            || is_hbr_ligature_yiddish(c)
+    pub const fn is_hbr_block(c: char) -> bool {
+        in_ranges(c, HBR_BLOCK_RANGES)
     }
     /// Checks if the given character is a HBR accent.
     ///
@@ -157,41 +166,10 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_accent(c: char) -> bool {
-        //matches!(c, '\u{0591}'..='\u{05AE}')
-        is_hbr_accent_etnahta(c)
-            || is_hbr_accent_segol(c)
-            || is_hbr_accent_shalshelet(c)
-            || is_hbr_accent_zaqef_qatan(c)
-            || is_hbr_accent_zaqef_gadol(c)
-            || is_hbr_accent_tipeha(c)
-            || is_hbr_accent_revia(c)
-            || is_hbr_accent_zarqa(c)
-            || is_hbr_accent_pashta(c)
-            || is_hbr_accent_yetiv(c)
-            || is_hbr_accent_tevir(c)
-            || is_hbr_accent_geresh(c)
-            || is_hbr_accent_geresh_muqdam(c)
-            || is_hbr_accent_gershayim(c)
-            || is_hbr_accent_qarney_para(c)
-            || is_hbr_accent_telisha_gedola(c)
-            || is_hbr_accent_pazer(c)
-            || is_hbr_accent_atnah_hafukh(c)
-            || is_hbr_accent_munah(c)
-            || is_hbr_accent_mahapakh(c)
-            || is_hbr_accent_merkha(c)
-            || is_hbr_accent_merkha_kefula(c)
-            || is_hbr_accent_darga(c)
-            || is_hbr_accent_qadma(c)
-            || is_hbr_accent_telisha_qetana(c)
-            || is_hbr_accent_yerah_ben_yomo(c)
-            || is_hbr_accent_ole(c)
-            || is_hbr_accent_iluy(c)
-            || is_hbr_accent_dehi(c)
-            || is_hbr_accent_zinor(c)
+    pub const fn is_hbr_accent(c: char) -> bool {
+        in_ranges(c, HBR_ACCENT_RANGES)
     }
 
-
     /// Checks if the given character is a HBR mark.
     ///
     /// # Example
@@ -210,9 +188,8 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_mark(c: char) -> bool {
-        // 05AF + 05C4 + 05C5
-        is_hbr_mark_lower_dot(c) || is_hbr_mark_upper_dot(c) || is_hbr_mark_masora_circle(c)
+    pub const fn is_hbr_mark(c: char) -> bool {
+        in_ranges(c, HBR_MARK_RANGES)
     }
     /// Checks if the given character is a HBR point.
     ///
@@ -232,8 +209,8 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_point(c: char) -> bool {
-        is_hbr_point_vowel(c) || is_hbr_point_semi_vowel(c) || is_hbr_point_reading_sign(c)
+    pub const fn is_hbr_point(c: char) -> bool {
+        in_ranges(c, HBR_POINT_RANGES)
     }
     /// Checks if the given character is a HBR point vowel.
     ///
@@ -253,7 +230,7 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_point_vowel(c: char) -> bool {
+    pub const fn is_hbr_point_vowel(c: char) -> bool {
         // 05B4 .. 05BB + 05C7
         //matches!(c, '\u{05B4}'..='\u{05BB}' | '\u{05C7}')
         is_hbr_point_hiriq(c)
@@ -284,7 +261,7 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_point_semi_vowel(c: char) -> bool {
+    pub const fn is_hbr_point_semi_vowel(c: char) -> bool {
         // 05B0 .. 05B3
         is_hbr_point_sheva(c)
             || is_hbr_point_hataf_segol(c)
@@ -310,7 +287,7 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_point_reading_sign(c: char) -> bool {
+    pub const fn is_hbr_point_reading_sign(c: char) -> bool {
         // 05BC .. 05BD + 05BF + 05C1 .. 05C2
         is_hbr_point_dagesh_or_mapiq(c)
             || is_hbr_point_meteg(c)
@@ -336,14 +313,8 @@ pub mod unicode_block_hebrew {
     ///   }
     /// }
     /// ```
-    pub fn is_hbr_punctuation(c: char) -> bool {
-        // 05BE + 05C0 + 05C3 + 05C6 + 05F3 + 05F4
-        is_hbr_punctuation_maqaf(c)
-            || is_hbr_punctuation_paseq(c)
-            || is_hbr_punctuation_sof_pasuq(c)
-            || is_hbr_punctuation_nun_hafukha(c)
-            || is_hbr_punctuation_geresh(c)
-            || is_hbr_punctuation_gershayim(c)
+    pub const fn is_hbr_punctuation(c: char) -> bool {
+        in_ranges(c, HBR_PUNCTUATION_RANGES)
     }
     /// Checks if the given character is a HBR consonant (final OR normal)
     ///
@@ -362,8 +333,8 @@ pub mod unicode_block_hebrew {
     ///   assert!(!is_hbr_consonant(c));
     /// }
     /// ```
-    pub fn is_hbr_consonant(c: char) -> bool {
-        is_hbr_consonant_normal(c) || is_hbr_consonant_final(c)
+    pub const fn is_hbr_consonant(c: char) -> bool {
+        in_ranges(c, HBR_CONSONANT_RANGES)
     }
     /// Checks if the given character is a HBR consonant normal.
     ///
@@ -378,30 +349,8 @@ pub mod unicode_block_hebrew {
     ///     assert!(is_hbr_consonant(c));
     /// }
     /// ```
-    pub fn is_hbr_consonant_normal(c: char) -> bool {
-        // 05D0..05D9 + 05DB..05DC + 05DE + 05E0..05E2 + 05E4 + 05E6..05EA
-        is_hbr_consonant_alef(c)
-            || is_hbr_consonant_bet(c)
-            || is_hbr_consonant_gimel(c)
-            || is_hbr_consonant_dalet(c)
-            || is_hbr_consonant_he(c)
-            || is_hbr_consonant_vav(c)
-            || is_hbr_consonant_zayin(c)
-            || is_hbr_consonant_het(c)
-            || is_hbr_consonant_tet(c)
-            || is_hbr_consonant_yod(c)
-            || is_hbr_consonant_kaf(c)
-            || is_hbr_consonant_lamed(c)
-            || is_hbr_consonant_mem(c)
-            || is_hbr_consonant_nun(c)
-            || is_hbr_consonant_samekh(c)
-            || is_hbr_consonant_ayin(c)
-            || is_hbr_consonant_pe(c)
-            || is_hbr_consonant_tsadi(c)
-            || is_hbr_consonant_qof(c)
-            || is_hbr_consonant_resh(c)
-            || is_hbr_consonant_shin(c)
-            || is_hbr_consonant_tav(c)
+    pub const fn is_hbr_consonant_normal(c: char) -> bool {
+        in_ranges(c, HBR_CONSONANT_NORMAL_RANGES)
     }
     /// Checks if the given character is a HBR consonant final.
     ///
@@ -416,13 +365,8 @@ pub mod unicode_block_hebrew {
     ///     assert!(is_hbr_consonant(c));
     /// }
     /// ```
-    pub fn is_hbr_consonant_final(c: char) -> bool {
-        // 05DA + 05DD + 05DF + 05E3 + 05E5
-        is_hbr_consonant_final_kaf(c)
-            || is_hbr_consonant_final_mem(c)
-            || is_hbr_consonant_final_nun(c)
-            || is_hbr_consonant_final_pe(c)
-            || is_hbr_consonant_final_tsadi(c)
+    pub const fn is_hbr_consonant_final(c: char) -> bool {
+        in_ranges(c, HBR_CONSONANT_FINAL_RANGES)
     }
 
     /// Checks if the given character is a HBR Yiddish ligature.
@@ -441,12 +385,37 @@ pub mod unicode_block_hebrew {
     ///   assert!(!is_hbr_ligature_yiddish(c));
     /// }
     /// ```
-    pub fn is_hbr_ligature_yiddish(c: char) -> bool {
-        // 05F0 .. 05F2
-        is_hbr_ligature_yiddisch_double_vav(c)
-            || is_hbr_ligature_yiddisch_double_yod(c)
-            || is_hbr_ligature_yiddisch_vav_yod(c)
+    pub const fn is_hbr_ligature_yiddish(c: char) -> bool {
+        in_ranges(c, HBR_LIGATURE_YIDDISH_RANGES)
     }
+
+    /// The characters for which [`is_hbr_point_vowel`] returns `true`, in code point order.
+    pub const HBR_POINT_VOWEL_CHARS: [char; 9] = [
+        '\u{05B4}', '\u{05B5}', '\u{05B6}', '\u{05B7}', '\u{05B8}', '\u{05B9}', '\u{05BA}',
+        '\u{05BB}', '\u{05C7}',
+    ];
+
+    /// The characters for which [`is_hbr_point_semi_vowel`] returns `true`, in code point order.
+    pub const HBR_POINT_SEMI_VOWEL_CHARS: [char; 4] =
+        ['\u{05B0}', '\u{05B1}', '\u{05B2}', '\u{05B3}'];
+
+    /// The characters for which [`is_hbr_point_reading_sign`] returns `true`, in code point order.
+    pub const HBR_POINT_READING_SIGN_CHARS: [char; 5] =
+        ['\u{05BC}', '\u{05BD}', '\u{05BF}', '\u{05C1}', '\u{05C2}'];
+
+    /// The characters for which [`is_hbr_point_vowel`] returns `true`, as ranges in code point order.
+    pub const HBR_POINT_VOWEL_RANGES: &[RangeInclusive<char>] =
+        &['\u{05B4}'..='\u{05BB}', '\u{05C7}'..='\u{05C7}'];
+
+    /// The characters for which [`is_hbr_point_semi_vowel`] returns `true`, as ranges in code point order.
+    pub const HBR_POINT_SEMI_VOWEL_RANGES: &[RangeInclusive<char>] = &['\u{05B0}'..='\u{05B3}'];
+
+    /// The characters for which [`is_hbr_point_reading_sign`] returns `true`, as ranges in code point order.
+    pub const HBR_POINT_READING_SIGN_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05BC}'..='\u{05BD}',
+        '\u{05BF}'..='\u{05BF}',
+        '\u{05C1}'..='\u{05C2}',
+    ];
 }
 
 pub mod unicode_block_alphabetic_presentation_forms {
@@ -464,12 +433,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// let non_apf_ch = '\u{FB13}'; // ARMENIAN SMALL LIGATURE MEN NOW
     /// assert!(!is_apf_block(non_apf_ch));
     /// ```
-    pub fn is_apf_block(c: char) -> bool {
-        is_apf_consonant_with_vowel(c)
-            || is_apf_point_reading_sign(c)
-            || is_apf_ligature(c)
-            || is_apf_alternative(c)
-            || is_apf_consonant_wide(c)
+    pub const fn is_apf_block(c: char) -> bool {
+        in_ranges(c, APF_BLOCK_RANGES)
     }
     /// Checks if the given character is an AFP consonant.
     ///
@@ -484,8 +449,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// let non_alternative = 'p';
     /// assert!(!is_apf_consonant(non_alternative));
     /// ```
-    pub fn is_apf_consonant(c: char) -> bool {
-        is_apf_consonant_wide(c) || is_apf_consonant_alternative_ayin(c)
+    pub const fn is_apf_consonant(c: char) -> bool {
+        in_ranges(c, APF_CONSONANT_RANGES)
     }
 
     /*
@@ -504,41 +469,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// let non_with_vowel = 'X';
     /// assert!(!is_apf_consonant_with_vowel(non_with_vowel));
     /// ```
-    pub fn is_apf_consonant_with_vowel(c: char) -> bool {
-        // U+FB1D + (U+FB2A .. U+FB36) + (U+FB38 .. U+FB3C) + U+FB3E + (U+FB40 .. U+FB41)
-        is_apf_consonant_vowel_yod_hiriq(c)
-            || is_apf_consonant_vowel_shin_shindot(c)
-            || is_apf_consonant_vowel_shin_sindot(c)
-            || is_apf_consonant_vowel_shin_dagesh_shindot(c)
-            || is_apf_consonant_vowel_shin_dagesh_sindot(c)
-            || is_apf_consonant_vowel_alef_patah(c)
-            || is_apf_consonant_vowel_alef_qamats(c)
-            || is_apf_consonant_vowel_alef_mapiq(c)
-            || is_apf_consonant_vowel_bet_dagesh(c)
-            || is_apf_consonant_vowel_gimmel_dagesh(c)
-            || is_apf_consonant_vowel_dalet_dagesh(c)
-            || is_apf_consonant_vowel_he_mapiq(c)
-            || is_apf_consonant_vowel_vav_dagesh(c)
-            || is_apf_consonant_vowel_zayin_dagesh(c)
-            || is_apf_consonant_vowel_tet_dagesh(c)
-            || is_apf_consonant_vowel_yod_dagesh(c)
-            || is_apf_consonant_vowel_final_kaf_dagesh(c)
-            || is_apf_consonant_vowel_kaf_dagesh(c)
-            || is_apf_consonant_vowel_lamed_dagesh(c)
-            || is_apf_consonant_vowel_mem_dagesh(c)
-            || is_apf_consonant_vowel_nun_dagesh(c)
-            || is_apf_consonant_vowel_samekh_dagesh(c)
-            || is_apf_consonant_vowel_final_pe_dagesh(c)
-            || is_apf_consonant_vowel_pe_dagesh(c)
-            || is_apf_consonant_vowel_tsadi_dagesh(c)
-            || is_apf_consonant_vowel_qof_dagesh(c)
-            || is_apf_consonant_vowel_resh_dagesh(c)
-            || is_apf_consonant_vowel_shin_dagesh(c)
-            || is_apf_consonant_vowel_tav_dagesh(c)
-            || is_apf_consonant_vowel_vav_holam(c)
-            || is_apf_consonant_vowel_bet_rafe(c)
-            || is_apf_consonant_vowel_kaf_rafe(c)
-            || is_apf_consonant_vowel_pe_rafe(c)
+    pub const fn is_apf_consonant_with_vowel(c: char) -> bool {
+        in_ranges(c, APF_CONSONANT_WITH_VOWEL_RANGES)
     }
 
     /*
@@ -554,9 +486,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// let reading_sign = '\u{FB1E}';
     /// assert!(is_apf_point_reading_sign(reading_sign));
     /// ```
-    pub fn is_apf_point_reading_sign(c: char) -> bool {
-        // U+FB1E
-        is_apf_point_judeo_spanish_varika(c)
+    pub const fn is_apf_point_reading_sign(c: char) -> bool {
+        in_ranges(c, APF_POINT_READING_SIGN_RANGES)
     }
 
     /*
@@ -572,9 +503,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// assert!(is_apf_ligature(liga));
     ///
     /// ```
-    pub fn is_apf_ligature(c: char) -> bool {
-        // U+FB4F + U+FB4F
-        is_apf_ligature_yiddisch_yod_yod_patah(c) || is_apf_ligature_alef_lamed(c)
+    pub const fn is_apf_ligature(c: char) -> bool {
+        in_ranges(c, APF_LIGATURE_RANGES)
     }
 
     /*
@@ -591,9 +521,8 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// assert!(is_apf_alternative(afp_alternative));
     ///
     /// ```
-    pub fn is_apf_alternative(c: char) -> bool {
-        // U+FB20  +  U+FB29
-        is_apf_consonant_alternative_ayin(c) || is_apf_letter_alternative_plus_sign(c)
+    pub const fn is_apf_alternative(c: char) -> bool {
+        in_ranges(c, APF_ALTERNATIVE_RANGES)
     }
 
     /*
@@ -612,18 +541,333 @@ pub mod unicode_block_alphabetic_presentation_forms {
     /// let non_afp_wide = '\u{FB29}';
     /// assert!(!is_apf_consonant_wide(non_afp_wide));
     /// ```
-    pub fn is_apf_consonant_wide(c: char) -> bool {
-        // U+FB21 .. U+FB28
-        is_apf_consonant_wide_alef(c)
-            || is_apf_consonant_wide_dalet(c)
-            || is_apf_consonant_wide_he(c)
-            || is_apf_consonant_wide_kaf(c)
-            || is_apf_consonant_wide_lamed(c)
-            || is_apf_consonant_wide_final_mem(c)
-            || is_apf_consonant_wide_resh(c)
-            || is_apf_consonant_wide_tav(c)
+    pub const fn is_apf_consonant_wide(c: char) -> bool {
+        in_ranges(c, APF_CONSONANT_WIDE_RANGES)
     }
 }
+pub mod block_membership {
+    use crate::*;
+
+    /// The membership of a code point in a Unicode block.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BlockMembership {
+        /// The code point lies in the block and is an assigned character.
+        Assigned,
+        /// The code point lies in the block but is reserved (unassigned).
+        Reserved,
+        /// The code point lies outside the block.
+        Outside,
+    }
+
+    /// Checks if the given code point lies in the range of the unicode block 'Hebrew'
+    /// (U+0590..U+05FF), assigned or reserved.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hbr_block_range;
+    ///
+    /// assert!(is_hbr_block_range('\u{0590}'));
+    /// assert!(is_hbr_block_range('א'));
+    /// assert!(!is_hbr_block_range('\u{0600}'));
+    /// ```
+    pub const fn is_hbr_block_range(c: char) -> bool {
+        c >= *UCD_BLOCK_HBR.start() && c <= *UCD_BLOCK_HBR.end()
+    }
+    /// Checks if the given code point is reserved (unassigned) in the unicode block 'Hebrew':
+    /// U+0590, U+05C8..U+05CF, U+05EB..U+05EE and U+05F5..U+05FF.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_hbr_block_reserved;
+    ///
+    /// assert!(is_hbr_block_reserved('\u{05C8}'));
+    /// assert!(!is_hbr_block_reserved('א'));
+    /// assert!(!is_hbr_block_reserved('a'));
+    /// ```
+    pub const fn is_hbr_block_reserved(c: char) -> bool {
+        in_ranges(c, HBR_BLOCK_RESERVED_RANGES)
+    }
+    /// Checks if the given code point lies in the Hebrew part of the unicode block
+    /// 'Alphabetic Presentation Forms' (U+FB1D..U+FB4F), assigned or reserved.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_apf_block_range;
+    ///
+    /// assert!(is_apf_block_range('\u{FB37}'));
+    /// assert!(!is_apf_block_range('\u{FB1C}'));
+    /// ```
+    pub const fn is_apf_block_range(c: char) -> bool {
+        c >= *UCD_BLOCK_APF_HEBREW.start() && c <= *UCD_BLOCK_APF_HEBREW.end()
+    }
+    /// Checks if the given code point is reserved (unassigned) in the Hebrew part of the
+    /// unicode block 'Alphabetic Presentation Forms': U+FB37, U+FB3D, U+FB3F, U+FB42 and U+FB45.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::is_apf_block_reserved;
+    ///
+    /// assert!(is_apf_block_reserved('\u{FB37}'));
+    /// assert!(!is_apf_block_reserved('\u{FB36}'));
+    /// ```
+    pub const fn is_apf_block_reserved(c: char) -> bool {
+        in_ranges(c, APF_BLOCK_RESERVED_RANGES)
+    }
+    /// Returns the membership of the given code point in the unicode block 'Hebrew'.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{hbr_block_membership, BlockMembership};
+    ///
+    /// assert_eq!(hbr_block_membership('א'), BlockMembership::Assigned);
+    /// assert_eq!(hbr_block_membership('\u{05EB}'), BlockMembership::Reserved);
+    /// assert_eq!(hbr_block_membership('a'), BlockMembership::Outside);
+    /// ```
+    pub const fn hbr_block_membership(c: char) -> BlockMembership {
+        if is_hbr_block(c) {
+            BlockMembership::Assigned
+        } else if is_hbr_block_range(c) {
+            BlockMembership::Reserved
+        } else {
+            BlockMembership::Outside
+        }
+    }
+    /// Returns the membership of the given code point in the Hebrew part of the unicode block
+    /// 'Alphabetic Presentation Forms'.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{apf_block_membership, BlockMembership};
+    ///
+    /// assert_eq!(apf_block_membership('\u{FB2A}'), BlockMembership::Assigned);
+    /// assert_eq!(apf_block_membership('\u{FB3D}'), BlockMembership::Reserved);
+    /// assert_eq!(apf_block_membership('א'), BlockMembership::Outside);
+    /// ```
+    pub const fn apf_block_membership(c: char) -> BlockMembership {
+        if is_apf_block(c) {
+            BlockMembership::Assigned
+        } else if is_apf_block_range(c) {
+            BlockMembership::Reserved
+        } else {
+            BlockMembership::Outside
+        }
+    }
+    /// Returns the membership of the given code point in the unicode script 'Hebrew', i.e. in
+    /// either of the two blocks.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{script_hbr_membership, BlockMembership};
+    ///
+    /// assert_eq!(script_hbr_membership('\u{FB2A}'), BlockMembership::Assigned);
+    /// assert_eq!(script_hbr_membership('\u{05FF}'), BlockMembership::Reserved);
+    /// assert_eq!(script_hbr_membership('a'), BlockMembership::Outside);
+    /// ```
+    pub const fn script_hbr_membership(c: char) -> BlockMembership {
+        match hbr_block_membership(c) {
+            BlockMembership::Outside => apf_block_membership(c),
+            membership => membership,
+        }
+    }
+}
+
+pub mod collection_members {
+    use crate::*;
+    use core::ops::RangeInclusive;
+
+    /// A collection of characters (e.g. [`is_hbr_accent`]) with its predicate, its members and
+    /// its ranges.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewCollection;
+    ///
+    /// let vowels = HebrewCollection::HbrPointVowel;
+    /// assert_eq!(vowels.chars().len(), 9);
+    /// assert_eq!(vowels.ranges(), &['\u{05B4}'..='\u{05BB}', '\u{05C7}'..='\u{05C7}']);
+    /// assert!(vowels.contains('\u{05B8}'));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum HebrewCollection {
+        /// See [`is_script_hbr`].
+        ScriptHbr,
+        /// See [`is_script_hbr_consonant`].
+        ScriptHbrConsonant,
+        /// See [`is_script_hbr_point`].
+        ScriptHbrPoint,
+        /// See [`is_script_hbr_point_reading_sign`].
+        ScriptHbrPointReadingSign,
+        /// See [`is_script_hbr_ligature`].
+        ScriptHbrLigature,
+        /// See [`is_script_hbr_ligature_yiddisch`].
+        ScriptHbrLigatureYiddisch,
+        /// See [`is_hbr_block`].
+        HbrBlock,
+        /// See [`is_hbr_accent`].
+        HbrAccent,
+        /// See [`is_hbr_mark`].
+        HbrMark,
+        /// See [`is_hbr_point`].
+        HbrPoint,
+        /// See [`is_hbr_point_vowel`].
+        HbrPointVowel,
+        /// See [`is_hbr_point_semi_vowel`].
+        HbrPointSemiVowel,
+        /// See [`is_hbr_point_reading_sign`].
+        HbrPointReadingSign,
+        /// See [`is_hbr_punctuation`].
+        HbrPunctuation,
+        /// See [`is_hbr_consonant`].
+        HbrConsonant,
+        /// See [`is_hbr_consonant_normal`].
+        HbrConsonantNormal,
+        /// See [`is_hbr_consonant_final`].
+        HbrConsonantFinal,
+        /// See [`is_hbr_ligature_yiddish`].
+        HbrLigatureYiddish,
+        /// See [`is_apf_block`].
+        ApfBlock,
+        /// See [`is_apf_consonant`].
+        ApfConsonant,
+        /// See [`is_apf_consonant_with_vowel`].
+        ApfConsonantWithVowel,
+        /// See [`is_apf_point_reading_sign`].
+        ApfPointReadingSign,
+        /// See [`is_apf_ligature`].
+        ApfLigature,
+        /// See [`is_apf_alternative`].
+        ApfAlternative,
+        /// See [`is_apf_consonant_wide`].
+        ApfConsonantWide,
+    }
+
+    impl HebrewCollection {
+        /// All collections.
+        pub const ALL: [HebrewCollection; 25] = [
+            HebrewCollection::ScriptHbr,
+            HebrewCollection::ScriptHbrConsonant,
+            HebrewCollection::ScriptHbrPoint,
+            HebrewCollection::ScriptHbrPointReadingSign,
+            HebrewCollection::ScriptHbrLigature,
+            HebrewCollection::ScriptHbrLigatureYiddisch,
+            HebrewCollection::HbrBlock,
+            HebrewCollection::HbrAccent,
+            HebrewCollection::HbrMark,
+            HebrewCollection::HbrPoint,
+            HebrewCollection::HbrPointVowel,
+            HebrewCollection::HbrPointSemiVowel,
+            HebrewCollection::HbrPointReadingSign,
+            HebrewCollection::HbrPunctuation,
+            HebrewCollection::HbrConsonant,
+            HebrewCollection::HbrConsonantNormal,
+            HebrewCollection::HbrConsonantFinal,
+            HebrewCollection::HbrLigatureYiddish,
+            HebrewCollection::ApfBlock,
+            HebrewCollection::ApfConsonant,
+            HebrewCollection::ApfConsonantWithVowel,
+            HebrewCollection::ApfPointReadingSign,
+            HebrewCollection::ApfLigature,
+            HebrewCollection::ApfAlternative,
+            HebrewCollection::ApfConsonantWide,
+        ];
+
+        /// Returns the members of the collection, in code point order.
+        pub const fn chars(self) -> &'static [char] {
+            match self {
+                HebrewCollection::ScriptHbr => &SCRIPT_HBR_CHARS,
+                HebrewCollection::ScriptHbrConsonant => &SCRIPT_HBR_CONSONANT_CHARS,
+                HebrewCollection::ScriptHbrPoint => &SCRIPT_HBR_POINT_CHARS,
+                HebrewCollection::ScriptHbrPointReadingSign => &SCRIPT_HBR_POINT_READING_SIGN_CHARS,
+                HebrewCollection::ScriptHbrLigature => &SCRIPT_HBR_LIGATURE_CHARS,
+                HebrewCollection::ScriptHbrLigatureYiddisch => &SCRIPT_HBR_LIGATURE_YIDDISCH_CHARS,
+                HebrewCollection::HbrBlock => &HBR_BLOCK_CHARS,
+                HebrewCollection::HbrAccent => &HBR_ACCENT_CHARS,
+                HebrewCollection::HbrMark => &HBR_MARK_CHARS,
+                HebrewCollection::HbrPoint => &HBR_POINT_CHARS,
+                HebrewCollection::HbrPointVowel => &HBR_POINT_VOWEL_CHARS,
+                HebrewCollection::HbrPointSemiVowel => &HBR_POINT_SEMI_VOWEL_CHARS,
+                HebrewCollection::HbrPointReadingSign => &HBR_POINT_READING_SIGN_CHARS,
+                HebrewCollection::HbrPunctuation => &HBR_PUNCTUATION_CHARS,
+                HebrewCollection::HbrConsonant => &HBR_CONSONANT_CHARS,
+                HebrewCollection::HbrConsonantNormal => &HBR_CONSONANT_NORMAL_CHARS,
+                HebrewCollection::HbrConsonantFinal => &HBR_CONSONANT_FINAL_CHARS,
+                HebrewCollection::HbrLigatureYiddish => &HBR_LIGATURE_YIDDISH_CHARS,
+                HebrewCollection::ApfBlock => &APF_BLOCK_CHARS,
+                HebrewCollection::ApfConsonant => &APF_CONSONANT_CHARS,
+                HebrewCollection::ApfConsonantWithVowel => &APF_CONSONANT_WITH_VOWEL_CHARS,
+                HebrewCollection::ApfPointReadingSign => &APF_POINT_READING_SIGN_CHARS,
+                HebrewCollection::ApfLigature => &APF_LIGATURE_CHARS,
+                HebrewCollection::ApfAlternative => &APF_ALTERNATIVE_CHARS,
+                HebrewCollection::ApfConsonantWide => &APF_CONSONANT_WIDE_CHARS,
+            }
+        }
+        /// Returns the members of the collection as ranges, in code point order.
+        pub const fn ranges(self) -> &'static [RangeInclusive<char>] {
+            match self {
+                HebrewCollection::ScriptHbr => SCRIPT_HBR_RANGES,
+                HebrewCollection::ScriptHbrConsonant => SCRIPT_HBR_CONSONANT_RANGES,
+                HebrewCollection::ScriptHbrPoint => SCRIPT_HBR_POINT_RANGES,
+                HebrewCollection::ScriptHbrPointReadingSign => SCRIPT_HBR_POINT_READING_SIGN_RANGES,
+                HebrewCollection::ScriptHbrLigature => SCRIPT_HBR_LIGATURE_RANGES,
+                HebrewCollection::ScriptHbrLigatureYiddisch => SCRIPT_HBR_LIGATURE_YIDDISCH_RANGES,
+                HebrewCollection::HbrBlock => HBR_BLOCK_RANGES,
+                HebrewCollection::HbrAccent => HBR_ACCENT_RANGES,
+                HebrewCollection::HbrMark => HBR_MARK_RANGES,
+                HebrewCollection::HbrPoint => HBR_POINT_RANGES,
+                HebrewCollection::HbrPointVowel => HBR_POINT_VOWEL_RANGES,
+                HebrewCollection::HbrPointSemiVowel => HBR_POINT_SEMI_VOWEL_RANGES,
+                HebrewCollection::HbrPointReadingSign => HBR_POINT_READING_SIGN_RANGES,
+                HebrewCollection::HbrPunctuation => HBR_PUNCTUATION_RANGES,
+                HebrewCollection::HbrConsonant => HBR_CONSONANT_RANGES,
+                HebrewCollection::HbrConsonantNormal => HBR_CONSONANT_NORMAL_RANGES,
+                HebrewCollection::HbrConsonantFinal => HBR_CONSONANT_FINAL_RANGES,
+                HebrewCollection::HbrLigatureYiddish => HBR_LIGATURE_YIDDISH_RANGES,
+                HebrewCollection::ApfBlock => APF_BLOCK_RANGES,
+                HebrewCollection::ApfConsonant => APF_CONSONANT_RANGES,
+                HebrewCollection::ApfConsonantWithVowel => APF_CONSONANT_WITH_VOWEL_RANGES,
+                HebrewCollection::ApfPointReadingSign => APF_POINT_READING_SIGN_RANGES,
+                HebrewCollection::ApfLigature => APF_LIGATURE_RANGES,
+                HebrewCollection::ApfAlternative => APF_ALTERNATIVE_RANGES,
+                HebrewCollection::ApfConsonantWide => APF_CONSONANT_WIDE_RANGES,
+            }
+        }
+        /// Returns the predicate of the collection.
+        pub fn predicate(self) -> fn(char) -> bool {
+            match self {
+                HebrewCollection::ScriptHbr => is_script_hbr,
+                HebrewCollection::ScriptHbrConsonant => is_script_hbr_consonant,
+                HebrewCollection::ScriptHbrPoint => is_script_hbr_point,
+                HebrewCollection::ScriptHbrPointReadingSign => is_script_hbr_point_reading_sign,
+                HebrewCollection::ScriptHbrLigature => is_script_hbr_ligature,
+                HebrewCollection::ScriptHbrLigatureYiddisch => is_script_hbr_ligature_yiddisch,
+                HebrewCollection::HbrBlock => is_hbr_block,
+                HebrewCollection::HbrAccent => is_hbr_accent,
+                HebrewCollection::HbrMark => is_hbr_mark,
+                HebrewCollection::HbrPoint => is_hbr_point,
+                HebrewCollection::HbrPointVowel => is_hbr_point_vowel,
+                HebrewCollection::HbrPointSemiVowel => is_hbr_point_semi_vowel,
+                HebrewCollection::HbrPointReadingSign => is_hbr_point_reading_sign,
+                HebrewCollection::HbrPunctuation => is_hbr_punctuation,
+                HebrewCollection::HbrConsonant => is_hbr_consonant,
+                HebrewCollection::HbrConsonantNormal => is_hbr_consonant_normal,
+                HebrewCollection::HbrConsonantFinal => is_hbr_consonant_final,
+                HebrewCollection::HbrLigatureYiddish => is_hbr_ligature_yiddish,
+                HebrewCollection::ApfBlock => is_apf_block,
+                HebrewCollection::ApfConsonant => is_apf_consonant,
+                HebrewCollection::ApfConsonantWithVowel => is_apf_consonant_with_vowel,
+                HebrewCollection::ApfPointReadingSign => is_apf_point_reading_sign,
+                HebrewCollection::ApfLigature => is_apf_ligature,
+                HebrewCollection::ApfAlternative => is_apf_alternative,
+                HebrewCollection::ApfConsonantWide => is_apf_consonant_wide,
+            }
+        }
+        /// Checks if the given character belongs to the collection.
+        pub fn contains(self, c: char) -> bool {
+            self.predicate()(c)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_functions {
     use crate::*;
@@ -986,4 +1230,116 @@ mod test_functions {
             }
         }
     }
+
+    fn assert_chars(chars: &[char], predicate: fn(char) -> bool) {
+        let members = (0x0590..0x0600)
+            .chain(0xFB1D..0xFB50)
+            .filter_map(char::from_u32)
+            .filter(|c| predicate(*c));
+        assert!(members.eq(chars.iter().copied()));
+    }
+
+    #[test]
+    fn test_collection_chars() {
+        assert_chars(&SCRIPT_HBR_CHARS, is_script_hbr);
+        assert_chars(&SCRIPT_HBR_CONSONANT_CHARS, is_script_hbr_consonant);
+        assert_chars(&SCRIPT_HBR_POINT_CHARS, is_script_hbr_point);
+        assert_chars(
+            &SCRIPT_HBR_POINT_READING_SIGN_CHARS,
+            is_script_hbr_point_reading_sign,
+        );
+        assert_chars(&SCRIPT_HBR_LIGATURE_CHARS, is_script_hbr_ligature);
+        assert_chars(
+            &SCRIPT_HBR_LIGATURE_YIDDISCH_CHARS,
+            is_script_hbr_ligature_yiddisch,
+        );
+        assert_chars(&HBR_BLOCK_CHARS, is_hbr_block);
+        assert_chars(&HBR_ACCENT_CHARS, is_hbr_accent);
+        assert_chars(&HBR_MARK_CHARS, is_hbr_mark);
+        assert_chars(&HBR_POINT_CHARS, is_hbr_point);
+        assert_chars(&HBR_POINT_VOWEL_CHARS, is_hbr_point_vowel);
+        assert_chars(&HBR_POINT_SEMI_VOWEL_CHARS, is_hbr_point_semi_vowel);
+        assert_chars(&HBR_POINT_READING_SIGN_CHARS, is_hbr_point_reading_sign);
+        assert_chars(&HBR_PUNCTUATION_CHARS, is_hbr_punctuation);
+        assert_chars(&HBR_CONSONANT_CHARS, is_hbr_consonant);
+        assert_chars(&HBR_CONSONANT_NORMAL_CHARS, is_hbr_consonant_normal);
+        assert_chars(&HBR_CONSONANT_FINAL_CHARS, is_hbr_consonant_final);
+        assert_chars(&HBR_LIGATURE_YIDDISH_CHARS, is_hbr_ligature_yiddish);
+        assert_chars(&APF_BLOCK_CHARS, is_apf_block);
+        assert_chars(&APF_CONSONANT_CHARS, is_apf_consonant);
+        assert_chars(&APF_CONSONANT_WITH_VOWEL_CHARS, is_apf_consonant_with_vowel);
+        assert_chars(&APF_POINT_READING_SIGN_CHARS, is_apf_point_reading_sign);
+        assert_chars(&APF_LIGATURE_CHARS, is_apf_ligature);
+        assert_chars(&APF_ALTERNATIVE_CHARS, is_apf_alternative);
+        assert_chars(&APF_CONSONANT_WIDE_CHARS, is_apf_consonant_wide);
+    }
+
+    #[test]
+    fn test_collection_members() {
+        for collection in HebrewCollection::ALL.iter().copied() {
+            let chars = collection.chars();
+            let ranges = collection.ranges();
+            assert!(chars.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(ranges
+                .iter()
+                .flat_map(|range| range.clone())
+                .eq(chars.iter().copied()));
+            for c in (0..0x0011_0000).filter_map(char::from_u32) {
+                let member = collection.contains(c);
+                assert_eq!(
+                    chars.binary_search(&c).is_ok(),
+                    member,
+                    "{:?} {:?}",
+                    collection,
+                    c
+                );
+                assert_eq!(ranges.iter().any(|range| range.contains(&c)), member);
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_membership() {
+        let reserved = ('\u{0590}'..='\u{05FF}')
+            .chain('\u{FB1D}'..='\u{FB4F}')
+            .filter(|c| is_hbr_block_reserved(*c) || is_apf_block_reserved(*c));
+        assert!(reserved.eq([
+            '\u{0590}', '\u{05C8}', '\u{05C9}', '\u{05CA}', '\u{05CB}', '\u{05CC}', '\u{05CD}',
+            '\u{05CE}', '\u{05CF}', '\u{05EB}', '\u{05EC}', '\u{05ED}', '\u{05EE}', '\u{05F5}',
+            '\u{05F6}', '\u{05F7}', '\u{05F8}', '\u{05F9}', '\u{05FA}', '\u{05FB}', '\u{05FC}',
+            '\u{05FD}', '\u{05FE}', '\u{05FF}', '\u{FB37}', '\u{FB3D}', '\u{FB3F}', '\u{FB42}',
+            '\u{FB45}'
+        ]));
+        for c in (0..0x0011_0000).filter_map(char::from_u32) {
+            let membership = script_hbr_membership(c);
+            assert_eq!(membership == BlockMembership::Assigned, is_script_hbr(c));
+            assert_eq!(
+                membership == BlockMembership::Reserved,
+                is_hbr_block_reserved(c) || is_apf_block_reserved(c)
+            );
+        }
+        assert_eq!(hbr_block_membership('\u{FB37}'), BlockMembership::Outside);
+        assert_eq!(apf_block_membership('\u{05C8}'), BlockMembership::Outside);
+    }
+
+    // a lookup table for U+0590 .. U+05FF built at compile time
+    const HBR_POINTS: [bool; 112] = {
+        let mut table = [false; 112];
+        let mut i = 0;
+        while i < HBR_BLOCK_CHARS.len() {
+            let c = HBR_BLOCK_CHARS[i];
+            table[(c as u32 - 0x0590) as usize] = is_hbr_point(c);
+            i += 1;
+        }
+        table
+    };
+
+    #[test]
+    fn test_const_predicates() {
+        const ALEF: bool = is_script_hbr_consonant('\u{05D0}') && !is_hbr_point('\u{05D0}');
+        assert!(ALEF);
+        for c in '\u{0590}'..='\u{05FF}' {
+            assert_eq!(HBR_POINTS[(c as u32 - 0x0590) as usize], is_hbr_point(c));
+        }
+    }
 }
@@ -0,0 +1,307 @@
+pub mod hebrew_fold {
+    use crate::*;
+    use core::cmp::Ordering;
+    use core::hash::{Hash, Hasher};
+    use core::str::Chars;
+
+    /// The strictness of a comparison of Hebrew text.
+    ///
+    /// Each level ignores everything the previous levels ignore, e.g.
+    /// `HebrewFold::IgnorePoints` ignores the accents as well.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum HebrewFold {
+        /// The text is compared as it is.
+        Exact,
+        /// The accents (cantillation marks) are ignored and the APF characters are compared
+        /// as their HBR letter and points (e.g. U+FB2E as alef with patah).
+        IgnoreCantillation,
+        /// All points and marks are ignored as well, so only the letters are compared.
+        IgnorePoints,
+        /// A final letter is equal to its medial form (e.g. ך and כ).
+        IgnoreFinalForms,
+        /// The plene and defective spellings are equal: a vav or yod is ignored unless it
+        /// starts a word (e.g. שלום and שלם, דויד and דוד).
+        ///
+        /// A doubled vav or yod (וו, יי) is the consonant of the plene spelling and is kept
+        /// once (e.g. תקווה and תִּקְוָה), a vav or yod before a final alef or he is kept as
+        /// well (e.g. הוא and היא, מצוה and מצה stay distinct). Other words that differ only
+        /// in a vav or yod still collide, e.g. שור and שיר.
+        IgnoreMatres,
+    }
+
+    /// Returns the key of the text at the given level: two texts are equal at that level if
+    /// their keys are equal.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{fold_key, HebrewFold};
+    ///
+    /// assert_eq!(fold_key("בְּרֵאשִׁ֖ית", HebrewFold::IgnoreCantillation), "בְּרֵאשִׁית");
+    /// assert_eq!(fold_key("בְּרֵאשִׁ֖ית", HebrewFold::IgnorePoints), "בראשית");
+    /// assert_eq!(fold_key("שָׁלוֹם", HebrewFold::IgnoreMatres), fold_key("שלם", HebrewFold::IgnoreMatres));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn fold_key(s: &str, level: HebrewFold) -> alloc::string::String {
+        Folded::new(s, level).chars().collect()
+    }
+
+    /// A text that is compared, ordered and hashed at a [`HebrewFold`] level, e.g. as the key
+    /// of a `HashMap`.
+    ///
+    /// The comparison does not allocate. Texts are meant to be compared at the same level.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{Folded, HebrewFold};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut dictionary = HashMap::new();
+    /// dictionary.insert(Folded::new("שָׁלוֹם", HebrewFold::IgnoreMatres), "peace");
+    ///
+    /// assert_eq!(dictionary.get(&Folded::new("שלם", HebrewFold::IgnoreMatres)), Some(&"peace"));
+    /// assert_eq!(dictionary.get(&Folded::new("שלום", HebrewFold::IgnoreMatres)), Some(&"peace"));
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub struct Folded<'a> {
+        text: &'a str,
+        level: HebrewFold,
+    }
+
+    impl<'a> Folded<'a> {
+        /// Wraps the text for comparisons at the given level.
+        pub fn new(text: &'a str, level: HebrewFold) -> Self {
+            Folded { text, level }
+        }
+        /// Returns the original text.
+        pub fn as_str(&self) -> &'a str {
+            self.text
+        }
+        /// Returns the level of the comparisons.
+        pub fn level(&self) -> HebrewFold {
+            self.level
+        }
+        /// Returns an iterator over the characters of the folded text.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{Folded, HebrewFold};
+        ///
+        /// let folded = Folded::new("מֶ֫לֶךְ", HebrewFold::IgnoreFinalForms);
+        /// assert!(folded.chars().eq("מלכ".chars()));
+        /// ```
+        pub fn chars(&self) -> FoldedChars<'a> {
+            FoldedChars {
+                chars: self.text.chars(),
+                level: self.level,
+                pending: ['\0'; 3],
+                index: 0,
+                len: 0,
+                word_start: true,
+                doubled: false,
+            }
+        }
+    }
+
+    impl<'a> PartialEq for Folded<'a> {
+        fn eq(&self, other: &Self) -> bool {
+            self.chars().eq(other.chars())
+        }
+    }
+
+    impl<'a> Eq for Folded<'a> {}
+
+    impl<'a> PartialOrd for Folded<'a> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<'a> Ord for Folded<'a> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.chars().cmp(other.chars())
+        }
+    }
+
+    impl<'a> Hash for Folded<'a> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            for c in self.chars() {
+                c.hash(state);
+            }
+            // ends the sequence, like the hash of a str
+            state.write_u8(0xFF);
+        }
+    }
+
+    /// An iterator over the characters of a [`Folded`] text, see [`Folded::chars`].
+    #[derive(Debug, Clone)]
+    pub struct FoldedChars<'a> {
+        chars: Chars<'a>,
+        level: HebrewFold,
+        // the folded characters of the current character
+        pending: [char; 3],
+        index: usize,
+        len: usize,
+        word_start: bool,
+        // the previous letter is a vav or yod that is doubled by the current one
+        doubled: bool,
+    }
+
+    impl<'a> FoldedChars<'a> {
+        fn push(&mut self, c: char) {
+            self.pending[self.len] = c;
+            self.len += 1;
+        }
+
+        fn push_mark(&mut self, c: char) {
+            let ignored = if is_hbr_accent(c) {
+                self.level >= HebrewFold::IgnoreCantillation
+            } else {
+                self.level >= HebrewFold::IgnorePoints
+            };
+            if !ignored {
+                self.push(c);
+            }
+        }
+
+        fn push_letter(&mut self, c: char) {
+            let word_start = self.word_start;
+            self.word_start = false;
+            let doubled = self.doubled;
+            self.doubled = false;
+            if self.level >= HebrewFold::IgnoreMatres
+                && !word_start
+                && (is_hbr_consonant_vav(c) || is_hbr_consonant_yod(c))
+            {
+                match self.next_letter() {
+                    _ if doubled => return,
+                    Some((next, _)) if next == c => self.doubled = true,
+                    Some(('\u{05D0}', true)) | Some(('\u{05D4}', true)) => {}
+                    _ => return,
+                }
+            }
+            if self.level >= HebrewFold::IgnoreFinalForms {
+                self.push(medial(c));
+            } else {
+                self.push(c);
+            }
+        }
+
+        // the next letter of the word (without points) and whether it ends the word
+        fn next_letter(&self) -> Option<(char, bool)> {
+            let mut letters = self
+                .chars
+                .clone()
+                .filter(|c| !is_hbr_combining_mark(*c) && !is_adj_combining_grapheme_joiner(*c))
+                .map(|c| match decompose_apf(c) {
+                    Some((base, _)) => Some(base),
+                    None if is_script_hbr_consonant(c) || is_script_hbr_ligature(c) => Some(c),
+                    None => None,
+                });
+            let next = letters.next()??;
+            Some((next, !matches!(letters.next(), Some(Some(_)))))
+        }
+
+        // folds the next character of the text into `pending`
+        fn fold(&mut self, c: char) {
+            self.index = 0;
+            self.len = 0;
+            if self.level == HebrewFold::Exact {
+                self.push(c);
+            } else if is_hbr_combining_mark(c) || is_adj_combining_grapheme_joiner(c) {
+                self.push_mark(c);
+            } else if is_apf_ligature_alef_lamed(c) && self.level >= HebrewFold::IgnorePoints {
+                self.push_letter('\u{05D0}');
+                self.push_letter('\u{05DC}');
+            } else if let Some((base, implied)) = decompose_apf(c) {
+                self.push_letter(base);
+                for mark in implied {
+                    self.push_mark(*mark);
+                }
+            } else if is_script_hbr_consonant(c) || is_script_hbr_ligature(c) {
+                self.push_letter(c);
+            } else {
+                self.word_start = true;
+                self.push(c);
+            }
+        }
+    }
+
+    impl<'a> Iterator for FoldedChars<'a> {
+        type Item = char;
+
+        fn next(&mut self) -> Option<char> {
+            while self.index == self.len {
+                let c = self.chars.next()?;
+                self.fold(c);
+            }
+            self.index += 1;
+            Some(self.pending[self.index - 1])
+        }
+    }
+
+    // the medial form of a final letter
+    pub(crate) fn medial(c: char) -> char {
+        match c {
+            '\u{05DA}' => '\u{05DB}',
+            '\u{05DD}' => '\u{05DE}',
+            '\u{05DF}' => '\u{05E0}',
+            '\u{05E3}' => '\u{05E4}',
+            '\u{05E5}' => '\u{05E6}',
+            _ => c,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_folded() {
+        let exact = |s| Folded::new(s, HebrewFold::Exact);
+        assert_eq!(exact("אָב"), exact("אָב"));
+        assert_ne!(exact("\u{FB2F}ב"), exact("אָב"));
+
+        let cantillation = |s| Folded::new(s, HebrewFold::IgnoreCantillation);
+        assert_eq!(cantillation("\u{FB2F}֣ב"), cantillation("אָב"));
+        assert_ne!(cantillation("אַב"), cantillation("אָב"));
+
+        let points = |s| Folded::new(s, HebrewFold::IgnorePoints);
+        assert_eq!(points("אַב"), points("אָב"));
+        assert_eq!(points("\u{FB4F}"), points("אל"));
+        assert_ne!(points("מֶלֶךְ"), points("מלכ"));
+
+        let finals = |s| Folded::new(s, HebrewFold::IgnoreFinalForms);
+        assert_eq!(finals("מֶלֶךְ"), finals("מלכ"));
+        assert_ne!(finals("שלום"), finals("שלם"));
+
+        let matres = |s| Folded::new(s, HebrewFold::IgnoreMatres);
+        assert_eq!(matres("שלום"), matres("שָׁלֹם"));
+        assert_eq!(matres("תקווה"), matres("תִּקְוָה"));
+        assert_eq!(matres("ויאמר"), matres("וַיֹּאמֶר"));
+        assert_ne!(matres("ויאמר"), matres("אמר"));
+        assert!(matres("ילד יוסף").chars().eq("ילד יספ".chars()));
+        assert_ne!(matres("הוא"), matres("היא"));
+        assert_ne!(matres("מצוה"), matres("מצה"));
+        assert_eq!(matres("מִצְוָה"), matres("מצוה"));
+        assert!(matres("תקווה").chars().eq("תקוה".chars()));
+        assert!(matres("עלייה").chars().eq("עליה".chars()));
+        // the collisions that remain
+        assert_eq!(matres("שור"), matres("שיר"));
+
+        // the order is the order of the folded characters
+        assert!(points("בַּיִת") < points("בית א"));
+        assert!(points("בָּא") < points("בֵּית"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_fold_key() {
+        assert_eq!(fold_key("שָׁל֑וֹם", HebrewFold::Exact), "שָׁל֑וֹם");
+        assert_eq!(fold_key("שָׁל֑וֹם", HebrewFold::IgnoreCantillation), "שָׁלוֹם");
+        assert_eq!(fold_key("שָׁל֑וֹם", HebrewFold::IgnorePoints), "שלום");
+        assert_eq!(fold_key("שָׁל֑וֹם", HebrewFold::IgnoreFinalForms), "שלומ");
+        assert_eq!(fold_key("שָׁל֑וֹם", HebrewFold::IgnoreMatres), "שלמ");
+        assert_eq!(fold_key("\u{FB2C}", HebrewFold::IgnoreCantillation), "שּׁ");
+    }
+}
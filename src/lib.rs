@@ -3,12 +3,20 @@
 // include README.md with additional tests
 #![doc = include_str!("../README.md")]
 
+// the text analysis functions need an allocator
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // contains all function API's
+mod adjacent;
 mod collections;
 mod unicode_block_apf;
 mod unicode_block_hbr;
 
 // re-export
+pub use self::adjacent::hebrew_adjacent::*;
+pub use self::collections::block_membership::*;
+pub use self::collections::collection_members::*;
 pub use self::collections::unicode_block_alphabetic_presentation_forms::*;
 pub use self::collections::unicode_block_hebrew::*;
 pub use self::collections::unicode_script_hebrew::*;
@@ -26,6 +34,52 @@ pub use self::unicode_block_apf::apf_letter_alternative::*;
 pub use self::unicode_block_apf::apf_ligature::*;
 pub use self::unicode_block_apf::apf_point::*;
 
+// contains the text analysis API's
+mod cantillation;
+mod charset;
+mod clusters;
+mod collation;
+mod fold;
+mod phonology;
+mod regex_class;
+mod search;
+mod spelling;
+mod ucd;
+mod validate;
+mod versions;
+mod vowels;
+
+// re-export
+pub use self::cantillation::accent_hierarchy::*;
+#[cfg(feature = "alloc")]
+pub use self::cantillation::dichotomy::*;
+pub use self::cantillation::meteg::*;
+pub use self::cantillation::trope::*;
+pub use self::cantillation::verse_words::*;
+pub use self::charset::hebrew_char_set::*;
+pub use self::clusters::apf_decomposition::*;
+pub use self::clusters::letter_clusters::*;
+pub use self::collation::hebrew_collation::*;
+pub use self::fold::hebrew_fold::*;
+pub use self::phonology::begadkefat::*;
+pub use self::phonology::dagesh::*;
+pub use self::phonology::matres::*;
+pub use self::phonology::qamats::*;
+pub use self::phonology::sheva::*;
+pub use self::phonology::syllables::*;
+pub use self::phonology::vav::*;
+#[cfg(feature = "alloc")]
+pub use self::regex_class::regex_classes::*;
+pub use self::search::hebrew_matcher::*;
+#[cfg(feature = "alloc")]
+pub use self::spelling::ktiv_male::*;
+pub use self::ucd::unicode_data::*;
+pub use self::validate::text_issues::*;
+#[cfg(feature = "alloc")]
+pub use self::validate::text_repair::*;
+pub use self::versions::unicode_versions::*;
+pub use self::vowels::hebrew_vowels::*;
+
 // contains the trait API
 mod trait_def;
 mod trait_impl;
@@ -0,0 +1,1386 @@
+pub mod syllables {
+    use super::letters::{letters, Letter, Letters, Role};
+    use crate::*;
+    use core::iter::Peekable;
+    use core::ops::Range;
+
+    /// Tells whether a syllable ends in a vowel or in a consonant.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SyllableKind {
+        /// The syllable ends in a vowel (possibly followed by a mater lectionis).
+        Open,
+        /// The syllable ends in a consonant (silent sheva, a final consonant or a doubled consonant).
+        Closed,
+    }
+
+    /// The stress of a syllable.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Stress {
+        /// The syllable carries an accent.
+        Primary,
+        /// The syllable carries a meteg.
+        Secondary,
+    }
+
+    /// A syllable of a pointed word.
+    ///
+    /// A consonant with a vocal sheva or a hataf vowel is part of the syllable of the following
+    /// vowel. A consonant with dagesh forte closes the preceding syllable and opens its own one.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Syllable {
+        /// The byte range of the syllable within the word.
+        pub range: Range<usize>,
+        /// Whether the syllable is open or closed.
+        pub kind: SyllableKind,
+        /// The stress of the syllable, if an accent or meteg marks it.
+        pub stress: Option<Stress>,
+        /// `true` if the syllable starts with a consonant that is doubled by a dagesh forte.
+        pub geminated: bool,
+        /// `true` if the syllable ends with a furtive patah (e.g. רוּחַ).
+        pub furtive_patah: bool,
+    }
+
+    /// An iterator over the syllables of a word, see [`syllables`].
+    #[derive(Debug, Clone)]
+    pub struct Syllables<'a> {
+        letters: Peekable<Letters<'a>>,
+        accents: usize,
+    }
+
+    /// Returns an iterator over the syllables of a pointed word.
+    ///
+    /// The vowel of a syllable is read from the vowel points, holam male (וֹ) and shuruk (וּ).
+    /// A yod after hiriq, tsere or segol, a he at the end of a word and an alef without vowel
+    /// are matres lectionis and leave the syllable open. Postpositive and prepositive accents
+    /// only mark the stress when they are not on the last (first) letter of the word.
+    ///
+    /// Words joined by maqaf are syllabified separately; non-letters are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{syllables, Stress, SyllableKind};
+    ///
+    /// let word = "הַשָּׁמַ֖יִם";
+    /// let texts: Vec<&str> = syllables(word).map(|s| &word[s.range]).collect();
+    /// assert_eq!(texts, ["הַ", "שָּׁ", "מַ֖", "יִם"]);
+    ///
+    /// let first = syllables(word).next().unwrap();
+    /// assert_eq!(first.kind, SyllableKind::Closed);
+    ///
+    /// let stressed = syllables(word).nth(2).unwrap();
+    /// assert_eq!(stressed.stress, Some(Stress::Primary));
+    /// ```
+    pub fn syllables(word: &str) -> Syllables<'_> {
+        Syllables {
+            letters: letters(word).peekable(),
+            accents: word.chars().filter(|c| is_hbr_accent(*c)).count(),
+        }
+    }
+
+    impl<'a> Iterator for Syllables<'a> {
+        type Item = Syllable;
+
+        fn next(&mut self) -> Option<Syllable> {
+            let first = loop {
+                let letter = self.letters.next()?;
+                if letter.role != Role::Boundary {
+                    break letter;
+                }
+            };
+            let mut syllable = Syllable {
+                range: first.cluster.range.clone(),
+                kind: SyllableKind::Open,
+                stress: stress(&first, self.accents),
+                geminated: first.dagesh_forte,
+                furtive_patah: false,
+            };
+            let mut nucleus = first.role == Role::Nucleus;
+            while let Some(letter) = self.letters.peek() {
+                match letter.role {
+                    Role::Boundary => break,
+                    Role::Nucleus | Role::Onset if nucleus => break,
+                    Role::Nucleus => nucleus = true,
+                    Role::Coda if nucleus => syllable.kind = SyllableKind::Closed,
+                    Role::Onset | Role::Coda | Role::Mater => {}
+                }
+                syllable.furtive_patah |= letter.furtive;
+                match (syllable.stress, stress(letter, self.accents)) {
+                    (Some(Stress::Primary), _) | (_, None) => {}
+                    (_, stress) => syllable.stress = stress,
+                }
+                syllable.range.end = letter.cluster.range.end;
+                self.letters.next();
+            }
+            if first.role == Role::Coda && !nucleus {
+                // consonants without any vowel (unpointed text)
+                syllable.kind = SyllableKind::Closed;
+            }
+            if let Some(letter) = self.letters.peek() {
+                if letter.dagesh_forte {
+                    syllable.kind = SyllableKind::Closed;
+                }
+            }
+            Some(syllable)
+        }
+    }
+
+    // the stress marked on a letter of a word with `accents` accents
+    fn stress(letter: &Letter<'_>, accents: usize) -> Option<Stress> {
+        let mut stress = None;
+        for c in letter.cluster.marks() {
+            if is_hbr_point_meteg(c) {
+                stress = stress.or(Some(Stress::Secondary));
+            } else if is_hbr_accent(c) {
+                let ignored = (is_prepositive(c) && letter.first)
+                    || (is_postpositive(c) && letter.last && accents > 1);
+                if !ignored {
+                    stress = Some(Stress::Primary);
+                }
+            }
+        }
+        stress
+    }
+
+    // segolta, zarqa, pashta, telisha qetana
+    fn is_postpositive(c: char) -> bool {
+        is_hbr_accent_segol(c)
+            || is_hbr_accent_zarqa(c)
+            || is_hbr_accent_zinor(c)
+            || is_hbr_accent_pashta(c)
+            || is_hbr_accent_telisha_qetana(c)
+    }
+
+    // yetiv, telisha gedola, dehi
+    fn is_prepositive(c: char) -> bool {
+        is_hbr_accent_yetiv(c) || is_hbr_accent_telisha_gedola(c) || is_hbr_accent_dehi(c)
+    }
+}
+
+pub mod sheva {
+    use super::letters::letters;
+    use crate::*;
+
+    /// The pronunciation of a sheva.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ShevaKind {
+        /// A sheva that is pronounced (sheva na), it opens a syllable.
+        Vocal,
+        /// A sheva that is not pronounced (sheva nah), it closes a syllable.
+        Silent,
+        /// A sheva after a short vowel that is followed by a begadkefat letter without
+        /// dagesh lene (sheva medium, e.g. מַלְכֵי).
+        Medial,
+    }
+
+    /// Classifies the sheva at byte `index` of a pointed word.
+    ///
+    /// A sheva is vocal when it is
+    /// - under the first letter of the word,
+    /// - under a letter with dagesh forte,
+    /// - the second of two shevas,
+    /// - under a letter that is followed by the same letter,
+    /// - after a long vowel (tsere, holam, hiriq/tsere male, shuruk or qamats with meteg),
+    /// - accompanied by a meteg (ga'ya).
+    ///
+    /// A sheva under the last letter of a word (e.g. final kaf) and the first of two
+    /// shevas at the end of a word are silent.
+    ///
+    /// Returns `None` if there is no sheva at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{sheva_kind, ShevaKind};
+    ///
+    /// let word = "יִשְׁמְרוּ";
+    /// let first = word.find('\u{05B0}').unwrap();
+    /// let second = word.rfind('\u{05B0}').unwrap();
+    /// assert_eq!(sheva_kind(word, first), Some(ShevaKind::Silent));
+    /// assert_eq!(sheva_kind(word, second), Some(ShevaKind::Vocal));
+    /// assert_eq!(sheva_kind(word, 0), None);
+    /// ```
+    pub fn sheva_kind(word: &str, index: usize) -> Option<ShevaKind> {
+        match word.get(index..).and_then(|rest| rest.chars().next()) {
+            Some(c) if is_hbr_point_sheva(c) => {}
+            _ => return None,
+        }
+        letters(word)
+            .find(|letter| letter.cluster.range.contains(&index))
+            .and_then(|letter| letter.sheva)
+    }
+}
+
+pub mod dagesh {
+    use super::letters::{is_begadkefat, letters, Role};
+    use crate::*;
+
+    /// The function of a dagesh (U+05BC).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DageshKind {
+        /// The consonant is doubled.
+        Forte,
+        /// A begadkefat letter is pronounced as a plosive.
+        Lene,
+        /// A word-final he (or an alef) is pronounced as a consonant.
+        Mapiq,
+        /// A vav without vowel is the vowel u.
+        Shuruk,
+    }
+
+    /// The position of a cluster within its word.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct DageshContext {
+        /// The cluster follows a (full) vowel.
+        pub after_vowel: bool,
+        /// The cluster is the last letter of the word.
+        pub word_final: bool,
+    }
+
+    /// Classifies the dagesh of a cluster.
+    ///
+    /// - a vav without vowel has a shuruk,
+    /// - an alef and a word-final he have a mapiq,
+    /// - a begadkefat letter has a dagesh forte after a vowel and a dagesh lene otherwise,
+    /// - all other letters have a dagesh forte.
+    ///
+    /// The dagesh of a precomposed APF character (e.g. U+FB34, U+FB35) is classified as well.
+    /// Returns `None` if the cluster has no dagesh.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{clusters, dagesh_kind, DageshContext, DageshKind};
+    ///
+    /// let he_mapiq = clusters("\u{FB34}").next().unwrap();
+    /// let context = DageshContext { after_vowel: true, word_final: true };
+    /// assert_eq!(dagesh_kind(&he_mapiq, context), Some(DageshKind::Mapiq));
+    ///
+    /// let vav_dagesh = clusters("\u{FB35}").next().unwrap();
+    /// assert_eq!(dagesh_kind(&vav_dagesh, DageshContext::default()), Some(DageshKind::Shuruk));
+    ///
+    /// let bet = clusters("בּ").next().unwrap();
+    /// assert_eq!(dagesh_kind(&bet, DageshContext::default()), Some(DageshKind::Lene));
+    /// let context = DageshContext { after_vowel: true, word_final: false };
+    /// assert_eq!(dagesh_kind(&bet, context), Some(DageshKind::Forte));
+    /// ```
+    pub fn dagesh_kind(cluster: &Cluster<'_>, context: DageshContext) -> Option<DageshKind> {
+        if !cluster.has_mark(is_hbr_point_dagesh_or_mapiq) {
+            return None;
+        }
+        let base = cluster.consonant()?;
+        let kind = if is_hbr_consonant_vav(base) && cluster.vowel().is_none() {
+            DageshKind::Shuruk
+        } else if is_hbr_consonant_alef(base) || (is_hbr_consonant_he(base) && context.word_final) {
+            DageshKind::Mapiq
+        } else if is_begadkefat(base) && !context.after_vowel {
+            DageshKind::Lene
+        } else {
+            DageshKind::Forte
+        };
+        Some(kind)
+    }
+
+    /// An iterator over the dageshes of a word, see [`dagesh_kinds`].
+    #[derive(Debug, Clone)]
+    pub struct DageshKinds<'a> {
+        letters: super::letters::Letters<'a>,
+    }
+
+    /// Returns an iterator over the byte ranges of the clusters with a dagesh and their
+    /// classification.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{dagesh_kinds, DageshKind};
+    ///
+    /// let kinds: Vec<DageshKind> = dagesh_kinds("בַּבַּיִת").map(|(_, kind)| kind).collect();
+    /// assert_eq!(kinds, [DageshKind::Lene, DageshKind::Forte]);
+    /// ```
+    pub fn dagesh_kinds(word: &str) -> DageshKinds<'_> {
+        DageshKinds {
+            letters: letters(word),
+        }
+    }
+
+    impl<'a> Iterator for DageshKinds<'a> {
+        type Item = (core::ops::Range<usize>, DageshKind);
+
+        fn next(&mut self) -> Option<(core::ops::Range<usize>, DageshKind)> {
+            for letter in &mut self.letters {
+                if letter.role == Role::Boundary {
+                    continue;
+                }
+                let context = DageshContext {
+                    after_vowel: letter.after_vowel,
+                    word_final: letter.last,
+                };
+                if let Some(kind) = dagesh_kind(&letter.cluster, context) {
+                    return Some((letter.cluster.range, kind));
+                }
+            }
+            None
+        }
+    }
+}
+
+pub mod qamats {
+    use super::letters::is_letter;
+    use crate::*;
+    use core::str::CharIndices;
+
+    // words (written without points) whose first qamats is a qamats qatan in an open or
+    // accented syllable, also after the prefixes ו ה ב כ ל מ ש
+    const QATAN_WORDS: [&str; 3] = ["כל", "קדשים", "שרשים"];
+
+    /// The pronunciation of a qamats (U+05B8).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum QamatsKind {
+        /// The long vowel a (qamats gadol).
+        Gadol,
+        /// The short vowel o (qamats qatan).
+        Qatan,
+    }
+
+    /// Classifies the qamats at byte `index` of a pointed text.
+    ///
+    /// A qamats is a qamats qatan when
+    /// - it is encoded as U+05C7,
+    /// - it is followed by a hataf qamats (e.g. צָהֳרַיִם),
+    /// - it is the first vowel of a known word (כָּל, קָדָשִׁים, שָׁרָשִׁים), also after the
+    ///   prefixes ו ה ב כ ל מ ש (e.g. לְכָל, מִכָּל),
+    /// - its syllable is closed and unstressed (e.g. חָכְמָה, כָּל־).
+    ///
+    /// A syllable is stressed when it carries an accent or a meteg. In a word without accents
+    /// the last syllable is stressed, unless the word is joined to the next one by a maqaf.
+    ///
+    /// Returns `None` if there is no qamats at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{qamats_kind, QamatsKind};
+    ///
+    /// let text = "חָכְמָה";
+    /// let first = text.find('\u{05B8}').unwrap();
+    /// let second = text.rfind('\u{05B8}').unwrap();
+    /// assert_eq!(qamats_kind(text, first), Some(QamatsKind::Qatan));
+    /// assert_eq!(qamats_kind(text, second), Some(QamatsKind::Gadol));
+    ///
+    /// // a meteg marks a qamats gadol
+    /// let text = "שָֽׁמְרָה";
+    /// assert_eq!(qamats_kind(text, text.find('\u{05B8}').unwrap()), Some(QamatsKind::Gadol));
+    /// ```
+    pub fn qamats_kind(text: &str, index: usize) -> Option<QamatsKind> {
+        match text.get(index..).and_then(|rest| rest.chars().next()) {
+            Some(c) if is_hbr_point_qamats_qatan(c) => return Some(QamatsKind::Qatan),
+            Some(c) if is_hbr_point_qamats(c) => {}
+            _ => return None,
+        }
+        let start = text[..index]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| is_separator(*c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let end = text[index..]
+            .find(is_separator)
+            .map_or(text.len(), |i| index + i);
+        let word = &text[start..end];
+        let index = index - start;
+        let bound = text[end..].starts_with('\u{05BE}');
+
+        if clusters(word)
+            .skip_while(|cluster| cluster.range.end <= index)
+            .nth(1)
+            .and_then(|cluster| cluster.vowel())
+            .map_or(false, is_hbr_point_hataf_qamats)
+        {
+            return Some(QamatsKind::Qatan);
+        }
+        if is_known_qatan(word, index) {
+            return Some(QamatsKind::Qatan);
+        }
+
+        let accented = word.chars().any(is_hbr_accent);
+        let mut syllables = syllables(word).peekable();
+        while let Some(syllable) = syllables.next() {
+            if !syllable.range.contains(&index) {
+                continue;
+            }
+            let last = syllables.peek().is_none();
+            let stressed = syllable.stress.is_some() || (last && !accented && !bound);
+            if syllable.kind == SyllableKind::Closed && !stressed {
+                return Some(QamatsKind::Qatan);
+            }
+            break;
+        }
+        Some(QamatsKind::Gadol)
+    }
+
+    // the qamats is on the first letter of a known word, possibly after prefixes (e.g. לְכָל)
+    fn is_known_qatan(word: &str, index: usize) -> bool {
+        let letters = || clusters(word).filter(is_letter);
+        for (position, cluster) in letters().enumerate() {
+            if cluster.range.contains(&index) {
+                return QATAN_WORDS.iter().any(|known| {
+                    letters()
+                        .skip(position)
+                        .filter_map(|cluster| cluster.consonant())
+                        .eq(known.chars())
+                });
+            }
+            if !cluster.base.map_or(false, is_prefix_letter) {
+                return false;
+            }
+        }
+        false
+    }
+
+    // the letters of the prefixes ו ה ב כ ל מ ש
+    fn is_prefix_letter(c: char) -> bool {
+        matches!(
+            c,
+            '\u{05D5}'
+                | '\u{05D4}'
+                | '\u{05D1}'
+                | '\u{05DB}'
+                | '\u{05DC}'
+                | '\u{05DE}'
+                | '\u{05E9}'
+        )
+    }
+
+    // a character that separates words: not a letter and not a mark
+    fn is_separator(c: char) -> bool {
+        !is_hbr_combining_mark(c) && !is_letter_char(c)
+    }
+
+    // a (possibly precomposed) letter
+    fn is_letter_char(c: char) -> bool {
+        let mut buffer = [0; 4];
+        clusters(c.encode_utf8(&mut buffer))
+            .next()
+            .map_or(false, |cluster| is_letter(&cluster))
+    }
+
+    /// An iterator over the qamats of a text, see [`qamats_kinds`].
+    #[derive(Debug, Clone)]
+    pub struct QamatsKinds<'a> {
+        text: &'a str,
+        chars: CharIndices<'a>,
+    }
+
+    /// Returns an iterator over the byte offsets of the qamats (U+05B8, U+05C7) of a text and
+    /// their classification, see [`qamats_kind`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{qamats_kinds, QamatsKind};
+    ///
+    /// let kinds: Vec<QamatsKind> = qamats_kinds("כָּל־הָאָרֶץ").map(|(_, kind)| kind).collect();
+    /// assert_eq!(kinds, [QamatsKind::Qatan, QamatsKind::Gadol, QamatsKind::Gadol]);
+    /// ```
+    pub fn qamats_kinds(text: &str) -> QamatsKinds<'_> {
+        QamatsKinds {
+            text,
+            chars: text.char_indices(),
+        }
+    }
+
+    impl<'a> Iterator for QamatsKinds<'a> {
+        type Item = (usize, QamatsKind);
+
+        fn next(&mut self) -> Option<(usize, QamatsKind)> {
+            let text = self.text;
+            self.chars
+                .find_map(|(index, _)| qamats_kind(text, index).map(|kind| (index, kind)))
+        }
+    }
+
+    /// Rewrites every qamats qatan written as U+05B8 to U+05C7, see [`qamats_kind`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::to_qamats_qatan;
+    ///
+    /// assert_eq!(
+    ///     to_qamats_qatan("כָּל־הָאָרֶץ"),
+    ///     "כָּל־הָאָרֶץ".replacen('\u{05B8}', "\u{05C7}", 1)
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_qamats_qatan(text: &str) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(text.len());
+        let mut copied = 0;
+        for (index, kind) in qamats_kinds(text) {
+            if kind == QamatsKind::Qatan && text[index..].starts_with('\u{05B8}') {
+                result.push_str(&text[copied..index]);
+                result.push('\u{05C7}');
+                copied = index + '\u{05B8}'.len_utf8();
+            }
+        }
+        result.push_str(&text[copied..]);
+        result
+    }
+}
+
+pub mod begadkefat {
+    use super::letters::{is_begadkefat, is_letter};
+    use crate::*;
+    use core::ops::Range;
+
+    /// The pronunciation of a begadkefat letter (bet, gimel, dalet, kaf, pe, tav).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BegadkefatState {
+        /// The letter is a stop (b, g, d, k, p, t).
+        Plosive,
+        /// The letter is spirantized (v, gh, dh, kh, f, th).
+        Fricative,
+    }
+
+    /// Decides whether the begadkefat letter of a cluster is a plosive or a fricative.
+    ///
+    /// - a rafe makes the letter a fricative, a dagesh makes it a plosive; this includes the
+    ///   precomposed APF forms (e.g. U+FB31 bet with dagesh, U+FB4C bet with rafe),
+    /// - without `prev_cluster` (at the start of a word) the letter is a plosive,
+    /// - after a vowel, a sheva or a mater lectionis (alef, he, vav, yod) the letter is a
+    ///   fricative, after a consonant without vowel it is a plosive.
+    ///
+    /// For the first letter of a word, `prev_cluster` is the last cluster of the previous word
+    /// when the two words are closely joined (see [`begadkefat_states`]).
+    ///
+    /// Returns `None` if the cluster is not a begadkefat letter.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{begadkefat_state, clusters, BegadkefatState};
+    ///
+    /// let mut clusters = clusters("אָב");
+    /// let alef = clusters.next().unwrap();
+    /// let bet = clusters.next().unwrap();
+    /// assert_eq!(begadkefat_state(&bet, Some(&alef)), Some(BegadkefatState::Fricative));
+    /// assert_eq!(begadkefat_state(&bet, None), Some(BegadkefatState::Plosive));
+    /// assert_eq!(begadkefat_state(&alef, None), None);
+    /// ```
+    pub fn begadkefat_state(
+        cluster: &Cluster<'_>,
+        prev_cluster: Option<&Cluster<'_>>,
+    ) -> Option<BegadkefatState> {
+        if !cluster.consonant().map_or(false, is_begadkefat) {
+            return None;
+        }
+        let state = if cluster.has_mark(is_hbr_point_rafe) {
+            BegadkefatState::Fricative
+        } else if cluster.has_mark(is_hbr_point_dagesh_or_mapiq) {
+            BegadkefatState::Plosive
+        } else if prev_cluster.map_or(false, |prev| {
+            prev.vowel().is_some() || is_vowel_letter(prev)
+        }) {
+            BegadkefatState::Fricative
+        } else {
+            BegadkefatState::Plosive
+        };
+        Some(state)
+    }
+
+    // alef, he, vav or yod without vowel
+    fn is_vowel_letter(cluster: &Cluster<'_>) -> bool {
+        cluster.vowel().is_none()
+            && cluster.consonant().map_or(false, |c| {
+                is_hbr_consonant_alef(c)
+                    || is_hbr_consonant_he(c)
+                    || is_hbr_consonant_vav(c)
+                    || is_hbr_consonant_yod(c)
+            })
+    }
+
+    // the last cluster of a word ends in a vowel (a sheva is not pronounced at the end of a word)
+    fn ends_in_vowel(cluster: &Cluster<'_>) -> bool {
+        match cluster.vowel() {
+            Some(vowel) => !is_hbr_point_sheva(vowel),
+            None => is_vowel_letter(cluster),
+        }
+    }
+
+    /// An iterator over the begadkefat letters of a text, see [`begadkefat_states`].
+    #[derive(Debug, Clone)]
+    pub struct BegadkefatStates<'a> {
+        words: VerseWords<'a>,
+        word: Option<(usize, Clusters<'a>)>,
+        // the preceding letter within the word, or the last letter of a joined preceding word
+        prev: Option<Cluster<'a>>,
+        // the current word has a conjunctive accent
+        joined: bool,
+    }
+
+    /// Returns an iterator over the byte ranges of the begadkefat letters of a text and their
+    /// pronunciation, see [`begadkefat_state`].
+    ///
+    /// A begadkefat letter without dagesh at the start of a word is a fricative when the
+    /// previous word ends in a vowel and carries a conjunctive accent. Words joined by maqaf
+    /// are treated as one word.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{begadkefat_states, BegadkefatState};
+    ///
+    /// // "and it was so": the bet of בֵן follows a vowel-final word with a conjunctive accent
+    /// let states: Vec<BegadkefatState> = begadkefat_states("וַֽיְהִי־כֵֽן").map(|(_, s)| s).collect();
+    /// assert_eq!(states, [BegadkefatState::Fricative]);
+    ///
+    /// let states: Vec<BegadkefatState> = begadkefat_states("לְכָה֙ דּוֹדִי").map(|(_, s)| s).collect();
+    /// assert_eq!(
+    ///     states,
+    ///     [BegadkefatState::Fricative, BegadkefatState::Plosive, BegadkefatState::Fricative]
+    /// );
+    ///
+    /// let states: Vec<BegadkefatState> = begadkefat_states("יִשְׂרָאֵ֣ל בְּנֵי").map(|(_, s)| s).collect();
+    /// assert_eq!(states, [BegadkefatState::Plosive]);
+    /// ```
+    pub fn begadkefat_states(text: &str) -> BegadkefatStates<'_> {
+        BegadkefatStates {
+            words: verse_words(text),
+            word: None,
+            prev: None,
+            joined: false,
+        }
+    }
+
+    impl<'a> Iterator for BegadkefatStates<'a> {
+        type Item = (Range<usize>, BegadkefatState);
+
+        fn next(&mut self) -> Option<(Range<usize>, BegadkefatState)> {
+            loop {
+                let (offset, clusters) = match &mut self.word {
+                    Some(word) => word,
+                    None => {
+                        let word = self.words.next()?;
+                        if !self.joined || !self.prev.as_ref().map_or(false, ends_in_vowel) {
+                            self.prev = None;
+                        }
+                        self.joined = matches!(word.accent(), Some(WordAccent::Conjunctive(_)));
+                        self.word = Some((word.range.start, clusters(word.text)));
+                        continue;
+                    }
+                };
+                let cluster = match clusters.next() {
+                    Some(cluster) => cluster,
+                    None => {
+                        self.word = None;
+                        continue;
+                    }
+                };
+                if !is_letter(&cluster) {
+                    if cluster.base.map_or(true, |c| !is_hbr_punctuation_maqaf(c)) {
+                        // a paseq or sof pasuq separates the words
+                        self.prev = None;
+                        self.joined = false;
+                    }
+                    continue;
+                }
+                let state = begadkefat_state(&cluster, self.prev.as_ref());
+                let range = *offset + cluster.range.start..*offset + cluster.range.end;
+                self.prev = Some(cluster);
+                if let Some(state) = state {
+                    return Some((range, state));
+                }
+            }
+        }
+    }
+}
+
+pub mod matres {
+    use super::letters::{letters, Role};
+    use crate::*;
+
+    /// The vowel that a vowel letter (mater lectionis) represents or supports.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MaterVowel {
+        /// Patah, e.g. the he of מַה.
+        Patah,
+        /// Qamats, e.g. the he of מַלְכָּה or the yod of the suffix -ָיו.
+        Qamats,
+        /// Segol, e.g. the he of שָׂדֶה or the yod of אֱלֹהֶיךָ.
+        Segol,
+        /// Tsere (tsere male), e.g. the yod of בֵּית.
+        Tsere,
+        /// Hiriq (hiriq male), e.g. the yod of עִיר.
+        Hiriq,
+        /// Holam (holam male), e.g. the vav of שָׁלוֹם or U+FB4B.
+        Holam,
+        /// Qubuts, e.g. before a quiescent alef.
+        Qubuts,
+        /// Shuruk, the vav with dagesh of סוּס or U+FB35.
+        Shuruk,
+    }
+
+    /// Checks if the letter at byte `index` of a pointed word is a vowel letter (mater
+    /// lectionis) and returns the vowel it supports.
+    ///
+    /// The vowel letters are
+    /// - a vav with holam (holam male, including U+FB4B) that follows a letter without vowel,
+    /// - a vav with dagesh and without vowel (shuruk, including U+FB35),
+    /// - a yod without vowel after hiriq, tsere or segol (hiriq male, tsere male) and the yod
+    ///   of the suffix -ָיו,
+    /// - a word-final he without mapiq after a vowel (e.g. after qamats or segol),
+    /// - a quiescent alef, i.e. an alef without vowel after a vowel.
+    ///
+    /// A vowel letter that follows another one supports the same vowel (e.g. the alef of
+    /// הוּא). Returns `None` for a consonant, for an alef after a silent sheva (e.g. חֵטְא)
+    /// and if there is no letter at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_mater_lectionis, MaterVowel};
+    ///
+    /// let word = "שָׁלוֹם";
+    /// assert_eq!(is_mater_lectionis(word, word.find('ו').unwrap()), Some(MaterVowel::Holam));
+    /// assert_eq!(is_mater_lectionis(word, word.find('ל').unwrap()), None);
+    ///
+    /// let word = "מַלְכָּה";
+    /// assert_eq!(is_mater_lectionis(word, word.find('ה').unwrap()), Some(MaterVowel::Qamats));
+    ///
+    /// // a consonantal vav
+    /// let word = "מִצְוָה";
+    /// assert_eq!(is_mater_lectionis(word, word.find('ו').unwrap()), None);
+    /// ```
+    pub fn is_mater_lectionis(word: &str, index: usize) -> Option<MaterVowel> {
+        // the vowel of the previous letter
+        let mut previous = None;
+        for letter in letters(word) {
+            let cluster = &letter.cluster;
+            let vav = cluster.base == Some('\u{05D5}');
+            let shuruk =
+                vav && cluster.vowel().is_none() && cluster.has_mark(is_hbr_point_dagesh_or_mapiq);
+            let (mater, vowel) = match letter.role {
+                Role::Mater if shuruk => (true, Some(MaterVowel::Shuruk)),
+                Role::Mater if vav => (true, Some(MaterVowel::Holam)),
+                Role::Mater => (true, previous),
+                // word-initial shuruk
+                Role::Nucleus if letter.first && shuruk => (true, Some(MaterVowel::Shuruk)),
+                Role::Nucleus => (false, cluster.vowel().and_then(mater_vowel)),
+                _ => (false, None),
+            };
+            if cluster.range.contains(&index) {
+                return if mater { vowel } else { None };
+            }
+            previous = vowel;
+        }
+        None
+    }
+
+    fn mater_vowel(point: char) -> Option<MaterVowel> {
+        let vowel = match point {
+            '\u{05B7}' => MaterVowel::Patah,
+            '\u{05B8}' | '\u{05C7}' => MaterVowel::Qamats,
+            '\u{05B6}' => MaterVowel::Segol,
+            '\u{05B5}' => MaterVowel::Tsere,
+            '\u{05B4}' => MaterVowel::Hiriq,
+            '\u{05B9}' | '\u{05BA}' => MaterVowel::Holam,
+            '\u{05BB}' => MaterVowel::Qubuts,
+            _ => return None,
+        };
+        Some(vowel)
+    }
+}
+
+pub mod vav {
+    use super::letters::{letters, Letter, Role};
+    use crate::*;
+    #[cfg(feature = "alloc")]
+    use core::ops::Range;
+
+    /// The function of a vav in pointed text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VavKind {
+        /// A vowel letter for the vowel o: vav with holam after a letter without vowel
+        /// (e.g. שָׁלוֹם, U+FB4B).
+        HolamMale,
+        /// A consonantal vav with the vowel holam (e.g. מִצְוֹת, עָוֺן).
+        ConsonantHolam,
+        /// A vowel letter for the vowel u: vav with dagesh and without vowel (e.g. סוּס, U+FB35).
+        Shuruk,
+        /// A consonantal vav with dagesh forte (e.g. צִוָּה).
+        DageshForte,
+        /// A consonantal vav without holam and dagesh (e.g. וְ, תִּקְוָה).
+        Consonant,
+    }
+
+    /// Classifies the vav at byte `index` of a pointed text.
+    ///
+    /// A dot on a vav is ambiguous: a holam is a holam male when the preceding letter has no
+    /// vowel, otherwise the vav is a consonant; U+05BA (holam haser for vav) always marks a
+    /// consonant. A dagesh is a shuruk when the vav has no vowel and starts the word or follows
+    /// a letter without vowel, otherwise it is a dagesh forte. The precomposed U+FB4B and U+FB35
+    /// are classified like their decomposition.
+    ///
+    /// Returns `None` if there is no vav at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{vav_kind, VavKind};
+    ///
+    /// let text = "שָׁלוֹם";
+    /// assert_eq!(vav_kind(text, text.find('ו').unwrap()), Some(VavKind::HolamMale));
+    /// let text = "מִצְוֹת";
+    /// assert_eq!(vav_kind(text, text.find('ו').unwrap()), Some(VavKind::ConsonantHolam));
+    /// let text = "צִוָּה";
+    /// assert_eq!(vav_kind(text, text.find('ו').unwrap()), Some(VavKind::DageshForte));
+    /// assert_eq!(vav_kind("סוּס", 2), Some(VavKind::Shuruk));
+    /// assert_eq!(vav_kind("סוּס", 0), None);
+    /// ```
+    pub fn vav_kind(text: &str, index: usize) -> Option<VavKind> {
+        letters(text)
+            .find(|letter| letter.cluster.range.contains(&index))
+            .and_then(|letter| kind(&letter))
+    }
+
+    /// Encodes every vav of a pointed text the way Unicode recommends: a consonantal vav with
+    /// holam uses U+05BA (holam haser for vav) instead of U+05B9, and the precomposed U+FB4B
+    /// and U+FB35 are decomposed into vav and holam or dagesh. The other characters are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::normalize_vav;
+    ///
+    /// assert_eq!(normalize_vav("מִצְוֹת"), "מִצְו\u{05BA}ת");
+    /// assert_eq!(normalize_vav("שָׁלוֹם"), "שָׁלוֹם");
+    /// assert_eq!(normalize_vav("ק\u{FB4B}ל"), "קוֹל");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalize_vav(text: &str) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(text.len());
+        for letter in letters(text) {
+            let kind = match kind(&letter) {
+                Some(kind) => kind,
+                None => {
+                    result.push_str(letter.cluster.text);
+                    continue;
+                }
+            };
+            result.push('\u{05D5}');
+            for mark in letter.cluster.marks() {
+                if kind == VavKind::ConsonantHolam && is_hbr_point_holam(mark) {
+                    result.push('\u{05BA}');
+                } else {
+                    result.push(mark);
+                }
+            }
+        }
+        result
+    }
+
+    // the byte ranges of the consonantal vavs with holam of a pointed text, in text order
+    #[cfg(feature = "alloc")]
+    pub(crate) fn consonant_holams(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+        letters(text)
+            .filter(|letter| kind(letter) == Some(VavKind::ConsonantHolam))
+            .map(|letter| letter.cluster.range)
+    }
+
+    fn kind(letter: &Letter<'_>) -> Option<VavKind> {
+        let cluster = &letter.cluster;
+        if cluster.base != Some('\u{05D5}') {
+            return None;
+        }
+        let kind = if cluster.has_mark(is_hbr_point_holam_haser_for_vav) {
+            VavKind::ConsonantHolam
+        } else if cluster.has_mark(is_hbr_point_holam) {
+            if letter.role == Role::Mater {
+                VavKind::HolamMale
+            } else {
+                VavKind::ConsonantHolam
+            }
+        } else if cluster.has_mark(is_hbr_point_dagesh_or_mapiq) {
+            let shuruk = cluster.vowel().is_none()
+                && (letter.role == Role::Mater || (letter.first && letter.role == Role::Nucleus));
+            if shuruk {
+                VavKind::Shuruk
+            } else {
+                VavKind::DageshForte
+            }
+        } else {
+            VavKind::Consonant
+        };
+        Some(kind)
+    }
+}
+
+// the analysis of the letters of a word, shared by the phonological functions
+pub(crate) mod letters {
+    use crate::*;
+
+    // bet, gimel, dalet, kaf, pe, tav (including the final forms)
+    pub(crate) fn is_begadkefat(c: char) -> bool {
+        is_hbr_consonant_bet(c)
+            || is_hbr_consonant_gimel(c)
+            || is_hbr_consonant_dalet(c)
+            || is_hbr_consonant_kaf(c)
+            || is_hbr_consonant_final_kaf(c)
+            || is_hbr_consonant_pe(c)
+            || is_hbr_consonant_final_pe(c)
+            || is_hbr_consonant_tav(c)
+    }
+
+    // the function of a letter within its syllable
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Role {
+        // a consonant with a vocal sheva or hataf vowel
+        Onset,
+        // a consonant with a (full) vowel
+        Nucleus,
+        // a consonant with a silent sheva or without vowel
+        Coda,
+        // a letter that represents (part of) the preceding vowel
+        Mater,
+        // a non-letter (space, maqaf, punctuation)
+        Boundary,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct Letter<'a> {
+        pub(crate) cluster: Cluster<'a>,
+        pub(crate) role: Role,
+        // the first/last letter of the word
+        pub(crate) first: bool,
+        pub(crate) last: bool,
+        // the letter follows a (full) vowel
+        pub(crate) after_vowel: bool,
+        pub(crate) dagesh_forte: bool,
+        pub(crate) furtive: bool,
+        pub(crate) sheva: Option<ShevaKind>,
+    }
+
+    // the state of the preceding letter
+    #[derive(Debug, Clone, Copy)]
+    struct Previous {
+        role: Role,
+        silent_sheva: bool,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct Letters<'a> {
+        clusters: Clusters<'a>,
+        previous: Option<Previous>,
+        // the vowel of the last nucleus (None for holam male and shuruk)
+        vowel: Option<char>,
+        long: bool,
+        // the next letter is a vav that carries the vowel of the current letter
+        carrier: bool,
+    }
+
+    pub(crate) fn letters(word: &str) -> Letters<'_> {
+        Letters {
+            clusters: clusters(word),
+            previous: None,
+            vowel: None,
+            long: false,
+            carrier: false,
+        }
+    }
+
+    pub(crate) fn is_letter(cluster: &Cluster<'_>) -> bool {
+        cluster.base.map_or(false, |c| {
+            is_hbr_consonant(c) || is_hbr_yod_triangle(c) || is_hbr_ligature_yiddish(c)
+        })
+    }
+
+    // a vav that is a holam male (without dagesh) or a shuruk (without vowel)
+    fn is_vowel_vav(cluster: &Cluster<'_>) -> bool {
+        if cluster.base != Some('\u{05D5}') {
+            return false;
+        }
+        let dagesh = cluster.has_mark(is_hbr_point_dagesh_or_mapiq);
+        match cluster.vowel() {
+            None => dagesh,
+            Some(vowel) => is_hbr_point_holam(vowel) && !dagesh,
+        }
+    }
+
+    impl<'a> Letters<'a> {
+        fn analyse(&mut self, cluster: Cluster<'a>) -> Letter<'a> {
+            let mut lookahead = self.clusters.clone();
+            let next = lookahead.next().filter(is_letter);
+            let next2 = next.as_ref().and(lookahead.next()).filter(is_letter);
+            let base = cluster.base.unwrap_or_default();
+            let vowel = cluster.vowel();
+            let dagesh = cluster.has_mark(is_hbr_point_dagesh_or_mapiq);
+            let first = self.previous.is_none();
+            let last = next.is_none();
+            let after_vowel = self
+                .previous
+                .map_or(false, |p| p.role == Role::Nucleus || p.role == Role::Mater);
+            let a_vowel = self.vowel.map_or(false, |v| {
+                is_hbr_point_patah(v) || is_hbr_point_qamats(v) || is_hbr_point_hataf_patah(v)
+            });
+            let mut dagesh_forte = false;
+            let mut furtive = false;
+            let mut sheva = None;
+
+            let role = if self.carrier {
+                self.carrier = false;
+                Role::Mater
+            } else if vowel.is_none()
+                && !dagesh
+                && !first
+                && self.is_mater(base, last, &next, &next2)
+            {
+                Role::Mater
+            } else {
+                let context = DageshContext {
+                    after_vowel,
+                    word_final: last,
+                };
+                dagesh_forte =
+                    after_vowel && dagesh_kind(&cluster, context) == Some(DageshKind::Forte);
+                match vowel {
+                    Some(v)
+                        if is_hbr_point_patah(v)
+                            && last
+                            && after_vowel
+                            && !a_vowel
+                            && (is_hbr_consonant_het(base)
+                                || is_hbr_consonant_ayin(base)
+                                || (is_hbr_consonant_he(base) && dagesh)) =>
+                    {
+                        furtive = true;
+                        Role::Coda
+                    }
+                    Some(v) if is_hbr_point_vowel(v) => {
+                        self.vowel = Some(v);
+                        // a qamats without meteg may be a qamats qatan
+                        self.long = vowel_info(v).map_or(false, |info| info.is_long())
+                            && (!is_hbr_point_qamats(v) || cluster.has_mark(is_hbr_point_meteg));
+                        Role::Nucleus
+                    }
+                    Some(v) if is_hbr_point_sheva(v) => {
+                        let kind = if last
+                            || next.as_ref().map_or(false, |n| {
+                                next2.is_none() && n.vowel().map_or(false, is_hbr_point_sheva)
+                            }) {
+                            // word-final sheva (e.g. under final kaf) and the first of two final shevas
+                            ShevaKind::Silent
+                        } else if first
+                            || self.previous.map_or(false, |p| p.silent_sheva)
+                            || dagesh_forte
+                            || cluster.has_mark(is_hbr_point_meteg)
+                            || next.as_ref().and_then(|n| n.base) == Some(base)
+                            || (after_vowel && self.long)
+                        {
+                            ShevaKind::Vocal
+                        } else if after_vowel
+                            && next.as_ref().map_or(false, |n| {
+                                n.base.map_or(false, is_begadkefat)
+                                    && !n.has_mark(is_hbr_point_dagesh_or_mapiq)
+                            })
+                        {
+                            // a begadkefat without dagesh lene after a short vowel
+                            ShevaKind::Medial
+                        } else {
+                            ShevaKind::Silent
+                        };
+                        sheva = Some(kind);
+                        if kind == ShevaKind::Vocal {
+                            Role::Onset
+                        } else {
+                            Role::Coda
+                        }
+                    }
+                    Some(_) => Role::Onset,
+                    None if next.as_ref().map_or(false, is_vowel_vav) => {
+                        // holam male or shuruk
+                        self.carrier = true;
+                        self.vowel = None;
+                        self.long = true;
+                        Role::Nucleus
+                    }
+                    None if first && base == '\u{05D5}' && dagesh => {
+                        // word-initial shuruk
+                        self.vowel = None;
+                        self.long = true;
+                        Role::Nucleus
+                    }
+                    None => Role::Coda,
+                }
+            };
+            self.previous = Some(Previous {
+                role,
+                silent_sheva: sheva.map_or(false, |kind| kind != ShevaKind::Vocal),
+            });
+            Letter {
+                cluster,
+                role,
+                first,
+                last,
+                after_vowel,
+                dagesh_forte,
+                furtive,
+                sheva,
+            }
+        }
+
+        // yod after hiriq, tsere or segol, word-final he, quiescent alef
+        fn is_mater(
+            &mut self,
+            base: char,
+            last: bool,
+            next: &Option<Cluster<'_>>,
+            next2: &Option<Cluster<'_>>,
+        ) -> bool {
+            let after_vowel = self
+                .previous
+                .map_or(false, |p| p.role == Role::Nucleus || p.role == Role::Mater);
+            if is_hbr_consonant_alef(base) {
+                return true;
+            }
+            if !after_vowel {
+                return false;
+            }
+            if is_hbr_consonant_yod(base) {
+                let vowel = self.vowel.unwrap_or_default();
+                if is_hbr_point_hiriq(vowel)
+                    || is_hbr_point_tsere(vowel)
+                    || is_hbr_point_segol(vowel)
+                {
+                    self.long = true;
+                    return true;
+                }
+                // the suffix -ָיו
+                return is_hbr_point_qamats(vowel)
+                    && next2.is_none()
+                    && next
+                        .as_ref()
+                        .map_or(false, |n| n.base == Some('\u{05D5}') && n.vowel().is_none());
+            }
+            is_hbr_consonant_he(base) && last
+        }
+    }
+
+    impl<'a> Iterator for Letters<'a> {
+        type Item = Letter<'a>;
+
+        fn next(&mut self) -> Option<Letter<'a>> {
+            let cluster = self.clusters.next()?;
+            if !is_letter(&cluster) {
+                self.previous = None;
+                self.vowel = None;
+                self.long = false;
+                self.carrier = false;
+                return Some(Letter {
+                    cluster,
+                    role: Role::Boundary,
+                    first: false,
+                    last: false,
+                    after_vowel: false,
+                    dagesh_forte: false,
+                    furtive: false,
+                    sheva: None,
+                });
+            }
+            Some(self.analyse(cluster))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn split(word: &str) -> impl Iterator<Item = &str> + '_ {
+        syllables(word).map(move |s| &word[s.range])
+    }
+
+    #[test]
+    fn test_syllables() {
+        assert!(split("בְּרֵאשִׁית").eq(["בְּרֵא", "שִׁית"]));
+        assert!(split("שָׁלוֹם").eq(["שָׁ", "לוֹם"]));
+        assert!(split("מִזְבֵּחַ").eq(["מִזְ", "בֵּחַ"]));
+        assert!(split("וּמֶלֶךְ").eq(["וּ", "מֶ", "לֶךְ"]));
+        assert!(split("קוּמִי").eq(["קוּ", "מִי"]));
+        assert!(split("יִשְׁמְרוּ").eq(["יִשְׁ", "מְרוּ"]));
+        assert!(split("הַלְלוּ").eq(["הַ", "לְלוּ"]));
+        assert!(split("אֵלָיו").eq(["אֵ", "לָיו"]));
+        assert!(split("מַלְכָּה").eq(["מַלְ", "כָּה"]));
+        assert!(split("אֱלֹהִים").eq(["אֱלֹ", "הִים"]));
+        assert!(split("").eq([""; 0]));
+    }
+
+    fn shevas(word: &str) -> impl Iterator<Item = ShevaKind> + '_ {
+        word.match_indices('\u{05B0}')
+            .map(move |(index, _)| sheva_kind(word, index).unwrap())
+    }
+
+    #[test]
+    fn test_sheva_kind() {
+        use ShevaKind::*;
+        // word-initial
+        assert!(shevas("בְּרֵאשִׁית").eq([Vocal]));
+        // after a long vowel
+        assert!(shevas("שֹׁמְרִים").eq([Vocal]));
+        assert!(shevas("שָֽׁמְרָה").eq([Vocal]));
+        // after a short vowel
+        assert!(shevas("יִשְׁמְרוּ").eq([Silent, Vocal]));
+        // under dagesh forte
+        assert!(shevas("הַמְּלָכִים").eq([Vocal]));
+        // before an identical consonant
+        assert!(shevas("הַלְלוּ").eq([Vocal]));
+        // under final kaf, two final shevas
+        assert!(shevas("מֶלֶךְ").eq([Silent]));
+        assert!(shevas("וַיֵּשְׁתְּ").eq([Silent, Silent]));
+        // before a begadkefat without dagesh lene
+        assert!(shevas("מַלְכֵי").eq([Medial]));
+        assert!(shevas("מַלְכָּה").eq([Silent]));
+    }
+
+    #[test]
+    fn test_dagesh_kind() {
+        use DageshKind::*;
+        let kinds = |word| dagesh_kinds(word).map(|(_, kind)| kind);
+        assert!(kinds("הַמֶּלֶךְ").eq([Forte]));
+        assert!(kinds("מַלְכָּה").eq([Lene]));
+        assert!(kinds("אַרְצָהּ").eq([Mapiq]));
+        assert!(kinds("\u{05D0}\u{05B7}\u{05E8}\u{05B0}\u{05E6}\u{05B8}\u{FB34}").eq([Mapiq]));
+        assert!(kinds("וּמֶלֶךְ").eq([Shuruk]));
+        assert!(kinds("ק\u{FB35}ם").eq([Shuruk]));
+        assert!(kinds("צִוָּה").eq([Forte]));
+        assert!(kinds("שָׁלוֹם").eq([DageshKind::Forte; 0]));
+        let cluster = clusters("־").next().unwrap();
+        assert_eq!(dagesh_kind(&cluster, DageshContext::default()), None);
+    }
+
+    fn qamats(text: &str) -> impl Iterator<Item = QamatsKind> + '_ {
+        qamats_kinds(text).map(|(_, kind)| kind)
+    }
+
+    #[test]
+    fn test_qamats_kind() {
+        use QamatsKind::*;
+        // closed and unstressed
+        assert!(qamats("חָכְמָה").eq([Qatan, Gadol]));
+        assert!(qamats("כָּל־").eq([Qatan]));
+        assert!(qamats("וַיָּ֣קָם").eq([Gadol, Qatan]));
+        // stressed
+        assert!(qamats("דָּבָר").eq([Gadol, Gadol]));
+        assert!(qamats("שָֽׁמְרָה").eq([Gadol, Gadol]));
+        // before hataf qamats
+        assert!(qamats("צָהֳרַיִם").eq([Qatan]));
+        // known words
+        assert!(qamats("כָּ֣ל").eq([Qatan]));
+        assert!(qamats("קָדָשִׁים").eq([Qatan, Gadol]));
+        // explicit qamats qatan
+        assert!(qamats("כׇּל").eq([Qatan]));
+        // known words after prefixes
+        assert!(qamats("לְכָל").eq([Qatan]));
+        assert!(qamats("בְּכָל").eq([Qatan]));
+        assert!(qamats("וְכָל").eq([Qatan]));
+        assert!(qamats("מִכָּל").eq([Qatan]));
+        assert!(qamats("וּבְכָל").eq([Qatan]));
+        assert!(qamats("לַקָּדָשִׁים").eq([Qatan, Gadol]));
+        assert!(qamats("לָכֶם").eq([Gadol]));
+        assert_eq!(qamats_kind("דָּבָר", 0), None);
+    }
+
+    #[test]
+    fn test_is_mater_lectionis() {
+        use MaterVowel::*;
+        let mater = |word: &str, letter: char| is_mater_lectionis(word, word.find(letter).unwrap());
+        // holam male and shuruk
+        assert_eq!(mater("שָׁלוֹם", 'ו'), Some(Holam));
+        assert_eq!(mater("ק\u{FB4B}ל", '\u{FB4B}'), Some(Holam));
+        assert_eq!(mater("סוּס", 'ו'), Some(Shuruk));
+        assert_eq!(mater("ס\u{FB35}ס", '\u{FB35}'), Some(Shuruk));
+        assert_eq!(mater("וּמֶלֶךְ", 'ו'), Some(Shuruk));
+        // hiriq, tsere and segol with yod
+        assert_eq!(mater("עִיר", 'י'), Some(Hiriq));
+        assert_eq!(mater("בֵּית", 'י'), Some(Tsere));
+        assert_eq!(mater("אֱלֹהֶיךָ", 'י'), Some(Segol));
+        assert_eq!(mater("אֵלָיו", 'י'), Some(Qamats));
+        // word-final he
+        assert_eq!(mater("מַלְכָּה", 'ה'), Some(Qamats));
+        assert_eq!(mater("שָׂדֶה", 'ה'), Some(Segol));
+        assert_eq!(mater("לָהּ", 'ה'), None);
+        // quiescent alef
+        assert_eq!(mater("רֹאשׁ", 'א'), Some(Holam));
+        assert_eq!(mater("בְּרֵאשִׁית", 'א'), Some(Tsere));
+        assert_eq!(mater("הוּא", 'א'), Some(Shuruk));
+        assert_eq!(mater("חֵטְא", 'א'), None);
+        // consonants
+        assert_eq!(mater("מִצְוָה", 'ו'), None);
+        assert_eq!(mater("מִצְוֹת", 'ו'), None);
+        assert_eq!(mater("יָד", 'י'), None);
+        assert_eq!(mater("אָב", 'א'), None);
+        assert_eq!(is_mater_lectionis("עִיר", 100), None);
+    }
+
+    #[test]
+    fn test_vav_kind() {
+        use VavKind::*;
+        let kind = |text: &str, vav: char| vav_kind(text, text.find(vav).unwrap());
+        assert_eq!(kind("שָׁלוֹם", 'ו'), Some(HolamMale));
+        assert_eq!(kind("ק\u{FB4B}ל", '\u{FB4B}'), Some(HolamMale));
+        assert_eq!(kind("מִצְוֹת", 'ו'), Some(ConsonantHolam));
+        assert_eq!(kind("עָו\u{05BA}ן", 'ו'), Some(ConsonantHolam));
+        assert_eq!(kind("עָוֹן", 'ו'), Some(ConsonantHolam));
+        assert_eq!(kind("סוּס", 'ו'), Some(Shuruk));
+        assert_eq!(kind("ס\u{FB35}ס", '\u{FB35}'), Some(Shuruk));
+        assert_eq!(kind("וּמֶלֶךְ", 'ו'), Some(Shuruk));
+        assert_eq!(kind("צִוָּה", 'ו'), Some(DageshForte));
+        assert_eq!(kind("צִ\u{FB35}\u{05B8}ה", '\u{FB35}'), Some(DageshForte));
+        assert_eq!(kind("וְהָיָה", 'ו'), Some(Consonant));
+        assert_eq!(kind("תִּקְוָה", 'ו'), Some(Consonant));
+        assert_eq!(vav_kind("שָׁלוֹם", 0), None);
+        assert_eq!(vav_kind("שָׁלוֹם", 100), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_normalize_vav() {
+        assert_eq!(normalize_vav("מִצְוֹת"), "מִצְו\u{05BA}ת");
+        assert_eq!(normalize_vav("עָוֹן"), "עָו\u{05BA}ן");
+        assert_eq!(normalize_vav("עָו\u{05BA}ן"), "עָו\u{05BA}ן");
+        assert_eq!(normalize_vav("שָׁלוֹם"), "שָׁלוֹם");
+        assert_eq!(normalize_vav("ס\u{FB35}ס ק\u{FB4B}ל"), "סוּס קוֹל");
+        assert_eq!(normalize_vav("\u{FB31}ית"), "\u{FB31}ית");
+        assert_eq!(normalize_vav(""), "");
+    }
+
+    #[test]
+    fn test_begadkefat_state() {
+        use BegadkefatState::*;
+        let states = |text| begadkefat_states(text).map(|(_, state)| state);
+        assert!(states("מַלְכָּה").eq([Plosive]));
+        assert!(states("מַלְכֵי").eq([Fricative]));
+        assert!(states("בְּרֵאשִׁית").eq([Plosive, Fricative]));
+        assert!(states("כָּתַבְתָּ").eq([Plosive, Fricative, Fricative, Plosive]));
+        // precomposed dagesh and rafe
+        assert!(states("\u{FB3B}\u{05B8}").eq([Plosive]));
+        assert!(states("אָ\u{FB4C}").eq([Fricative]));
+        // across words
+        assert!(states("וַיְהִי־בֹקֶר").eq([Fricative]));
+        assert!(states("לְכָה֙ דּוֹדִי").eq([Fricative, Plosive, Fricative]));
+        assert!(states("הָיָ֣ה בָנוּ").eq([Fricative]));
+        assert!(states("הָיָ֑ה בָנוּ").eq([Plosive]));
+        assert!(states("מֶ֣לֶךְ בָנוּ").eq([Fricative, Plosive]));
+        let range = begadkefat_states("הָיָ֣ה בָנוּ").next().unwrap().0;
+        assert_eq!(&"הָיָ֣ה בָנוּ"[range], "בָ");
+    }
+
+    #[test]
+    fn test_syllable_kinds() {
+        let kinds = syllables("מִזְבֵּחַ").map(|s| s.kind);
+        assert!(kinds.eq([SyllableKind::Closed, SyllableKind::Closed]));
+        let last = syllables("מִזְבֵּחַ").last().unwrap();
+        assert!(last.furtive_patah);
+        let kinds = syllables("סוּסָה").map(|s| s.kind);
+        assert!(kinds.eq([SyllableKind::Open, SyllableKind::Open]));
+        let second = syllables("הַשָּׁמַיִם").nth(1).unwrap();
+        assert!(second.geminated);
+    }
+
+    #[test]
+    fn test_syllable_stress() {
+        // pashta written twice: the first one marks the stress
+        let stresses = syllables("תֹ֙הוּ֙").map(|s| s.stress);
+        assert!(stresses.eq([Some(Stress::Primary), None]));
+        let stresses = syllables("הָאָֽרֶץ").map(|s| s.stress);
+        assert!(stresses.eq([None, Some(Stress::Secondary), None]));
+        // maqaf separates the words
+        assert_eq!(syllables("עַל־פְּנֵ֣י").count(), 2);
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(feature = "alloc")]
+pub mod regex_classes {
+    use crate::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Write;
+    use core::ops::RangeInclusive;
+
+    // the letters of a word: consonants, ligatures and precomposed letters
+    const LETTERS: [HebrewCollection; 3] = [
+        HebrewCollection::ScriptHbrConsonant,
+        HebrewCollection::ScriptHbrLigature,
+        HebrewCollection::ApfConsonantWithVowel,
+    ];
+
+    // the marks of a pointed letter: points, accents and marks
+    const MARKS: [HebrewCollection; 3] = [
+        HebrewCollection::ScriptHbrPoint,
+        HebrewCollection::HbrAccent,
+        HebrewCollection::HbrMark,
+    ];
+
+    /// Renders the union of collections as a character class in the syntax of the `regex`
+    /// crate (and most other regex engines), e.g. `[\x{05B4}-\x{05BB}\x{05C7}]`.
+    ///
+    /// Overlapping and adjacent ranges are merged. An empty list of collections gives a
+    /// class that matches nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{regex_class, HebrewCollection};
+    ///
+    /// let class = regex_class(&[HebrewCollection::HbrPointVowel]);
+    /// assert_eq!(class, r"[\x{05B4}-\x{05BB}\x{05C7}]");
+    ///
+    /// let class = regex_class(&[HebrewCollection::HbrPointSemiVowel, HebrewCollection::HbrPointVowel]);
+    /// assert_eq!(class, r"[\x{05B0}-\x{05BB}\x{05C7}]");
+    /// ```
+    pub fn regex_class(collections: &[HebrewCollection]) -> String {
+        let mut ranges: Vec<RangeInclusive<char>> = collections
+            .iter()
+            .flat_map(|collection| collection.ranges().iter().cloned())
+            .collect();
+        if ranges.is_empty() {
+            return String::from(r"[^\x{0}-\x{10FFFF}]");
+        }
+        ranges.sort_by_key(|range| *range.start());
+        let mut merged: Vec<RangeInclusive<char>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() as u32 <= *last.end() as u32 + 1 => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        let mut class = String::from("[");
+        for range in merged {
+            // writing to a String does not fail
+            let _ = if range.start() == range.end() {
+                write!(class, r"\x{{{:04X}}}", *range.start() as u32)
+            } else {
+                write!(
+                    class,
+                    r"\x{{{:04X}}}-\x{{{:04X}}}",
+                    *range.start() as u32,
+                    *range.end() as u32
+                )
+            };
+        }
+        class.push(']');
+        class
+    }
+
+    /// Returns a pattern that matches a word of unpointed Hebrew letters.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::hebrew_word_pattern;
+    ///
+    /// assert!(hebrew_word_pattern().ends_with("]+"));
+    /// ```
+    pub fn hebrew_word_pattern() -> String {
+        let mut pattern = regex_class(&LETTERS);
+        pattern.push('+');
+        pattern
+    }
+
+    /// Returns a pattern that matches a word of Hebrew letters that may carry points, accents
+    /// and marks.
+    pub fn pointed_hebrew_word_pattern() -> String {
+        let mut pattern = String::from("(?:");
+        pattern.push_str(&regex_class(&LETTERS));
+        pattern.push_str(&regex_class(&MARKS));
+        pattern.push_str("*)+");
+        pattern
+    }
+
+    /// Returns a pattern that matches an acronym: letters with a gershayim (U+05F4, or the
+    /// ASCII quotation mark that is often typed instead) before the last letter (e.g. תנ״ך).
+    pub fn acronym_pattern() -> String {
+        let letters = regex_class(&LETTERS);
+        let mut pattern = String::new();
+        pattern.push_str(&letters);
+        pattern.push_str("+[\\x{05F4}\"]");
+        pattern.push_str(&letters);
+        pattern
+    }
+
+    /// Returns a compiled [`hebrew_word_pattern`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::hebrew_word_regex;
+    ///
+    /// let words: Vec<&str> = hebrew_word_regex().find_iter("שלום, עולם!").map(|m| m.as_str()).collect();
+    /// assert_eq!(words, ["שלום", "עולם"]);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn hebrew_word_regex() -> regex_crate::Regex {
+        compile(&hebrew_word_pattern())
+    }
+
+    /// Returns a compiled [`pointed_hebrew_word_pattern`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::pointed_hebrew_word_regex;
+    ///
+    /// let text = "בְּרֵאשִׁ֖ית בָּרָ֣א";
+    /// let words: Vec<&str> = pointed_hebrew_word_regex().find_iter(text).map(|m| m.as_str()).collect();
+    /// assert_eq!(words, ["בְּרֵאשִׁ֖ית", "בָּרָ֣א"]);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn pointed_hebrew_word_regex() -> regex_crate::Regex {
+        compile(&pointed_hebrew_word_pattern())
+    }
+
+    /// Returns a compiled [`acronym_pattern`].
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::acronym_regex;
+    ///
+    /// assert!(acronym_regex().is_match("התנ״ך"));
+    /// assert!(acronym_regex().is_match("צה\"ל"));
+    /// assert!(!acronym_regex().is_match("ספר"));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn acronym_regex() -> regex_crate::Regex {
+        compile(&acronym_pattern())
+    }
+
+    #[cfg(feature = "regex")]
+    fn compile(pattern: &str) -> regex_crate::Regex {
+        regex_crate::Regex::new(pattern).expect("the patterns of the crate are valid")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_regex_class() {
+        assert_eq!(
+            regex_class(&[HebrewCollection::HbrAccent]),
+            r"[\x{0591}-\x{05AE}]"
+        );
+        assert_eq!(
+            regex_class(&[HebrewCollection::HbrMark, HebrewCollection::HbrAccent]),
+            r"[\x{0591}-\x{05AF}\x{05C4}-\x{05C5}]"
+        );
+        assert_eq!(regex_class(&[]), r"[^\x{0}-\x{10FFFF}]");
+        assert_eq!(
+            regex_class(&HebrewCollection::ALL),
+            r"[\x{0591}-\x{05C7}\x{05D0}-\x{05EA}\x{05EF}-\x{05F4}\x{FB1D}-\x{FB36}\x{FB38}-\x{FB3C}\x{FB3E}\x{FB40}-\x{FB41}\x{FB43}-\x{FB44}\x{FB46}-\x{FB4F}]"
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regexes() {
+        for collection in HebrewCollection::ALL.iter().copied() {
+            let class = regex_crate::Regex::new(&regex_class(&[collection])).unwrap();
+            for c in ('\u{0590}'..='\u{05FF}').chain('\u{FB1D}'..='\u{FB4F}') {
+                let mut buffer = [0; 4];
+                assert_eq!(
+                    class.is_match(c.encode_utf8(&mut buffer)),
+                    collection.contains(c)
+                );
+            }
+        }
+        let words = pointed_hebrew_word_regex();
+        assert_eq!(words.find("וַֽיְהִי־אוֹר").unwrap().as_str(), "וַֽיְהִי");
+        assert!(!hebrew_word_regex().is_match("abc"));
+        assert_eq!(acronym_regex().find("ראו רש״י").unwrap().as_str(), "רש״י");
+    }
+}
@@ -0,0 +1,243 @@
+pub mod hebrew_matcher {
+    use crate::*;
+    use core::ops::Range;
+
+    /// The options of a [`HebrewMatcher`].
+    ///
+    /// Points, accents and marks are always ignored and the APF characters always match their
+    /// HBR letters.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct MatchOptions {
+        /// Let a final letter match its medial form and vice versa (e.g. ך and כ).
+        pub equate_final_forms: bool,
+    }
+
+    /// A search for a pattern in Hebrew text that ignores points (niqqud), accents and marks.
+    ///
+    /// The pattern and the text are compared letter by letter: points, accents, marks and the
+    /// combining grapheme joiner are skipped, and the APF characters are compared as their
+    /// HBR letters (e.g. U+FB35 as vav, U+FB4F as alef and lamed). The matches are byte ranges
+    /// of the original text that include the marks of the last matched letter, so they can be
+    /// highlighted as they are. A match that starts or ends within U+FB4F includes the whole
+    /// ligature.
+    ///
+    /// A pattern without letters matches nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewMatcher;
+    ///
+    /// let text = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים";
+    /// let matcher = HebrewMatcher::new("ברא");
+    /// let found: Vec<&str> = matcher.find_iter(text).map(|range| &text[range]).collect();
+    /// assert_eq!(found, ["בְּרֵא", "בָּרָ֣א"]);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HebrewMatcher<'p> {
+        pattern: &'p str,
+        options: MatchOptions,
+    }
+
+    impl<'p> HebrewMatcher<'p> {
+        /// Returns a matcher for the given pattern with the default options.
+        pub fn new(pattern: &'p str) -> Self {
+            HebrewMatcher::with_options(pattern, MatchOptions::default())
+        }
+        /// Returns a matcher for the given pattern with the given options.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{HebrewMatcher, MatchOptions};
+        ///
+        /// let options = MatchOptions { equate_final_forms: true };
+        /// let matcher = HebrewMatcher::with_options("מלך", options);
+        /// assert_eq!(matcher.find("מַלְכֵי אֶרֶץ"), Some(0..12));
+        /// assert!(!HebrewMatcher::new("מלך").is_match("מַלְכֵי אֶרֶץ"));
+        /// ```
+        pub fn with_options(pattern: &'p str, options: MatchOptions) -> Self {
+            HebrewMatcher { pattern, options }
+        }
+        /// Checks if the pattern occurs in the haystack.
+        pub fn is_match(&self, haystack: &str) -> bool {
+            self.find(haystack).is_some()
+        }
+        /// Returns the byte range of the first match in the haystack.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::HebrewMatcher;
+        ///
+        /// let text = "וַיֹּ֥אמֶר אֱלֹהִ֖ים";
+        /// let range = HebrewMatcher::new("אלהים").find(text).unwrap();
+        /// assert_eq!(&text[range], "אֱלֹהִ֖ים");
+        /// ```
+        pub fn find(&self, haystack: &str) -> Option<Range<usize>> {
+            self.find_iter(haystack).next()
+        }
+        /// Returns an iterator over the byte ranges of the non-overlapping matches in the
+        /// haystack.
+        pub fn find_iter<'h>(&self, haystack: &'h str) -> HebrewMatches<'p, 'h> {
+            HebrewMatches {
+                matcher: *self,
+                haystack,
+                position: 0,
+            }
+        }
+
+        // the end of the match that starts at `start` after the first `skip` letters of the
+        // character there (the alef of the alef lamed ligature), if any
+        fn match_at(&self, haystack: &str, start: usize, skip: usize) -> Option<usize> {
+            let options = self.options;
+            let mut pattern = self
+                .pattern
+                .chars()
+                .flat_map(|c| letters(c, options))
+                .peekable();
+            pattern.peek()?;
+            let mut rest = haystack[start..].char_indices();
+            let mut end = start;
+            let mut skip = skip;
+            while pattern.peek().is_some() {
+                let (index, c) = rest.next()?;
+                for letter in letters(c, options).skip(skip) {
+                    match pattern.next() {
+                        Some(expected) if expected == letter => {}
+                        Some(_) => return None,
+                        None => break,
+                    }
+                }
+                skip = 0;
+                end = start + index + c.len_utf8();
+            }
+            // the marks of the last letter belong to the match
+            for (index, c) in rest {
+                if !is_ignored(c) {
+                    break;
+                }
+                end = start + index + c.len_utf8();
+            }
+            Some(end)
+        }
+    }
+
+    /// An iterator over the matches of a [`HebrewMatcher`], see [`HebrewMatcher::find_iter`].
+    #[derive(Debug, Clone)]
+    pub struct HebrewMatches<'p, 'h> {
+        matcher: HebrewMatcher<'p>,
+        haystack: &'h str,
+        position: usize,
+    }
+
+    impl<'p, 'h> Iterator for HebrewMatches<'p, 'h> {
+        type Item = Range<usize>;
+
+        fn next(&mut self) -> Option<Range<usize>> {
+            while let Some(c) = self.haystack[self.position..].chars().next() {
+                let start = self.position;
+                self.position += c.len_utf8();
+                if is_ignored(c) {
+                    continue;
+                }
+                // a match may start at any letter of the character, e.g. at the lamed of the
+                // alef lamed ligature
+                for skip in 0..letters(c, self.matcher.options).count() {
+                    if let Some(end) = self.matcher.match_at(self.haystack, start, skip) {
+                        self.position = end;
+                        return Some(start..end);
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    // the characters that are skipped when comparing
+    fn is_ignored(c: char) -> bool {
+        is_hbr_combining_mark(c) || is_adj_combining_grapheme_joiner(c)
+    }
+
+    // the letters a character is compared as: none for an ignored character, two for the
+    // alef lamed ligature
+    fn letters(c: char, options: MatchOptions) -> Letters {
+        let (chars, len) = if is_ignored(c) {
+            ([c, c], 0)
+        } else if is_apf_ligature_alef_lamed(c) {
+            (['\u{05D0}', '\u{05DC}'], 2)
+        } else {
+            let base = decompose_apf(c).map_or(c, |(base, _)| base);
+            let base = if options.equate_final_forms {
+                medial(base)
+            } else {
+                base
+            };
+            ([base, base], 1)
+        };
+        Letters {
+            chars,
+            len,
+            index: 0,
+        }
+    }
+
+    struct Letters {
+        chars: [char; 2],
+        len: usize,
+        index: usize,
+    }
+
+    impl Iterator for Letters {
+        type Item = char;
+
+        fn next(&mut self) -> Option<char> {
+            if self.index < self.len {
+                self.index += 1;
+                Some(self.chars[self.index - 1])
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_hebrew_matcher() {
+        let text = "וַיַּ֧רְא אֱלֹהִ֛ים אֶת־הָא֖וֹר כִּי־ט֑וֹב";
+        let matcher = HebrewMatcher::new("אור");
+        assert_eq!(matcher.find(text).map(|range| &text[range]), Some("א֖וֹר"));
+        // points in the pattern are ignored as well
+        assert!(HebrewMatcher::new("טוֹב").is_match(text));
+        assert!(!HebrewMatcher::new("טובה").is_match(text));
+        // a pattern without letters matches nothing
+        assert_eq!(HebrewMatcher::new("\u{05B8}").find(text), None);
+        assert_eq!(HebrewMatcher::new("").find(text), None);
+
+        // presentation forms match their letters
+        let text = "\u{FB4B}\u{FB4F}\u{05B8}";
+        assert_eq!(HebrewMatcher::new("ואל").find(text), Some(0..8));
+        assert_eq!(HebrewMatcher::new("וא").find(text), Some(0..8));
+        assert_eq!(HebrewMatcher::new("בּ").find("\u{FB31}\u{05B0}"), Some(0..5));
+        // also from the lamed of the alef lamed ligature
+        assert_eq!(HebrewMatcher::new("א").find("\u{FB4F}"), Some(0..3));
+        assert_eq!(HebrewMatcher::new("ל").find("\u{FB4F}"), Some(0..3));
+        assert_eq!(HebrewMatcher::new("לה").find("\u{FB4F}ה"), Some(0..5));
+        assert_eq!(HebrewMatcher::new("לא").find("\u{FB4F}ה"), None);
+
+        // the combining grapheme joiner between marks is skipped
+        let text = "יְרוּשָׁלַ\u{05B4}\u{034F}ם";
+        assert!(HebrewMatcher::new("ירושלם").is_match(text));
+
+        let matches = HebrewMatcher::new("לא").find_iter("לֹא לא לָלא");
+        assert!(matches.eq([0..6, 7..11, 16..20]));
+
+        let options = MatchOptions {
+            equate_final_forms: true,
+        };
+        assert!(HebrewMatcher::with_options("כ", options).is_match("ךְ"));
+        assert!(HebrewMatcher::with_options("ם", options).is_match("\u{FB3E}"));
+        assert!(!HebrewMatcher::new("ם").is_match("\u{FB3E}"));
+    }
+}
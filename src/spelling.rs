@@ -0,0 +1,275 @@
+#[cfg(feature = "alloc")]
+pub mod ktiv_male {
+    use crate::phonology::letters::{letters, Letter, Role};
+    use crate::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// Converts pointed text into the unpointed plene spelling (ktiv male) following the
+    /// rules of the Academy of the Hebrew Language.
+    ///
+    /// The points, accents and marks are removed and the vowels are written with vowel
+    /// letters:
+    ///
+    /// - holam, qubuts, qamats qatan and hataf qamats are written with vav, except for a holam
+    ///   before a vowel letter (e.g. לֹא, פֹּה) and for the word כָּל;
+    /// - hiriq is written with yod, except before a letter with sheva, before a yod, before a
+    ///   quiescent alef (e.g. רִאשׁוֹן) and in the prefix מִ (before a dagesh), also behind the
+    ///   prefixes ו ה ש כ ל ב (e.g. וּמִבֵּית);
+    /// - a consonantal vav within a word is doubled whatever its point (e.g. עַוְלָה, שָׁוְא),
+    ///   unless it is next to a vav that is a vowel letter, also one written for the vowel of
+    ///   the previous letter (e.g. מְצֻוֶּה);
+    /// - a consonantal yod with a vowel (other than hiriq) within a word is doubled, unless
+    ///   it is next to a vav or yod that is a vowel letter;
+    /// - a consonantal yod with hiriq is doubled in the dual ending ַיִם (e.g. עֵינַיִם) and
+    ///   after a vowel letter (e.g. גּוֹיִם), but not in the words בַּיִת and מַיִם.
+    ///
+    /// Vowel letters that are already written (holam male, shuruk, hiriq male, ...) are kept.
+    /// Words that the Academy spells by exception are not known.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::to_ktiv_male;
+    ///
+    /// assert_eq!(to_ktiv_male("שֻׁלְחָן"), "שולחן");
+    /// assert_eq!(to_ktiv_male("סִפּוּר"), "סיפור");
+    /// assert_eq!(to_ktiv_male("חָכְמָה"), "חוכמה");
+    /// assert_eq!(to_ktiv_male("תִּקְוָה"), "תקווה");
+    /// assert_eq!(to_ktiv_male("כָּל־הָעָם"), "כל־העם");
+    /// ```
+    pub fn to_ktiv_male(pointed: &str) -> String {
+        let letters: Vec<Letter> = letters(pointed).collect();
+        let mut result = String::with_capacity(pointed.len());
+        // the vowel of the previous letter is a qamats qatan
+        let mut after_qatan = false;
+        // the vowel letter written after the previous letter (or the previous letter itself)
+        let mut after_vowel_letter = None;
+        for (index, letter) in letters.iter().enumerate() {
+            let base = match letter.cluster.base {
+                Some(base) => base,
+                None => continue,
+            };
+            if letter.role == Role::Boundary {
+                after_qatan = false;
+                after_vowel_letter = None;
+                if !is_adj_combining_grapheme_joiner(base) {
+                    result.push(base);
+                }
+                continue;
+            }
+            result.push(base);
+            if letter.role == Role::Mater {
+                after_qatan = false;
+                after_vowel_letter = Some(base);
+                continue;
+            }
+            let previous = index
+                .checked_sub(1)
+                .and_then(|previous| letters.get(previous))
+                .filter(|previous| previous.role != Role::Boundary);
+            let next = letters
+                .get(index + 1)
+                .filter(|next| next.role != Role::Boundary);
+            let vowel = letter.cluster.vowel();
+            let qatan = vowel.map_or(false, |vowel| is_qamats_qatan(pointed, letter, vowel));
+
+            let vowel_letter = match vowel {
+                Some(v) if is_hbr_point_holam(v) || is_hbr_point_holam_haser_for_vav(v) => {
+                    if next.map_or(false, |next| next.role == Role::Mater) {
+                        None
+                    } else {
+                        Some('\u{05D5}')
+                    }
+                }
+                Some(v) if is_hbr_point_qubuts(v) => Some('\u{05D5}'),
+                Some(v) if is_hbr_point_hataf_qamats(v) && !after_qatan => Some('\u{05D5}'),
+                Some(_) if qatan => {
+                    let kol = is_hbr_consonant_kaf(base)
+                        && next.map_or(false, |next| {
+                            next.last && next.cluster.base == Some('\u{05DC}')
+                        });
+                    if kol {
+                        None
+                    } else {
+                        Some('\u{05D5}')
+                    }
+                }
+                Some(v) if is_hbr_point_hiriq(v) => {
+                    let before_yod_or_sheva = next.map_or(false, |next| {
+                        next.cluster.base == Some('\u{05D9}')
+                            || next.cluster.vowel().map_or(false, is_hbr_point_sheva)
+                    });
+                    let before_quiescent_alef = next.map_or(false, |next| {
+                        next.role == Role::Mater && next.cluster.base == Some('\u{05D0}')
+                    });
+                    let prefix_min = is_hbr_consonant_mem(base)
+                        && follows_prefixes(&letters[..index])
+                        && next.map_or(false, |next| {
+                            next.cluster.has_mark(is_hbr_point_dagesh_or_mapiq)
+                        });
+                    if is_hbr_consonant_yod(base)
+                        || before_yod_or_sheva
+                        || before_quiescent_alef
+                        || prefix_min
+                    {
+                        None
+                    } else {
+                        Some('\u{05D9}')
+                    }
+                }
+                _ => None,
+            };
+
+            let full_vowel = vowel.map_or(false, |v| {
+                is_hbr_point_vowel(v) || (is_hbr_point_semi_vowel(v) && !is_hbr_point_sheva(v))
+            });
+            if vowel_letter.is_none() && !letter.first && !letter.last {
+                let is_vowel_vav = |other: Option<&Letter>| {
+                    other.map_or(false, |other| {
+                        other.role == Role::Mater && other.cluster.base == Some('\u{05D5}')
+                    })
+                };
+                let is_vowel_yod = |other: Option<&Letter>| {
+                    other.map_or(false, |other| {
+                        other.role == Role::Mater && other.cluster.base == Some('\u{05D9}')
+                    })
+                };
+                let double = if is_hbr_consonant_vav(base) {
+                    after_vowel_letter != Some('\u{05D5}') && !is_vowel_vav(next)
+                } else if is_hbr_consonant_yod(base) && vowel.map_or(false, is_hbr_point_hiriq) {
+                    // the dual ending ַיִם, except in מַיִם, and a yod after a vowel letter
+                    let dual = previous.map_or(false, |previous| {
+                        previous.cluster.vowel().map_or(false, is_hbr_point_patah)
+                            && !is_mayim(&letters[..index])
+                    }) && next.map_or(false, |next| {
+                        next.last && next.cluster.base == Some('\u{05DD}')
+                    });
+                    dual || after_vowel_letter.is_some()
+                } else if is_hbr_consonant_yod(base) {
+                    full_vowel
+                        && after_vowel_letter.is_none()
+                        && !is_vowel_vav(next)
+                        && !is_vowel_yod(next)
+                } else {
+                    false
+                };
+                if double {
+                    result.push(base);
+                }
+            }
+            if let Some(vowel_letter) = vowel_letter {
+                result.push(vowel_letter);
+            }
+            after_qatan = qatan;
+            after_vowel_letter = vowel_letter;
+        }
+        result
+    }
+
+    // the letters of the word up to the yod (the end of `before`) are מַ of מַיִם, possibly
+    // after prefixes: the mem starts the word, has the dagesh of the article or follows a
+    // prefix with sheva or shuruk (e.g. הַמַּיִם, לְמַיִם, but not שָׁמַיִם)
+    fn is_mayim(before: &[Letter]) -> bool {
+        let (mem, before) = match before.split_last() {
+            Some((mem, before)) if mem.cluster.base == Some('\u{05DE}') => (mem, before),
+            _ => return false,
+        };
+        let after_prefix = before.last().map_or(false, |prefix| {
+            prefix.cluster.vowel().map_or(false, is_hbr_point_sheva)
+                || (prefix.cluster.base == Some('\u{05D5}')
+                    && prefix.cluster.vowel().is_none()
+                    && prefix.cluster.has_mark(is_hbr_point_dagesh_or_mapiq))
+        });
+        follows_prefixes(before)
+            && (mem.first || mem.cluster.has_mark(is_hbr_point_dagesh_or_mapiq) || after_prefix)
+    }
+
+    // the letters of the word before a letter (the end of `before`) are prefixes ו ה ש כ ל ב
+    fn follows_prefixes(before: &[Letter]) -> bool {
+        for letter in before.iter().rev() {
+            if !letter.cluster.base.map_or(false, |base| {
+                matches!(
+                    base,
+                    '\u{05D5}' | '\u{05D4}' | '\u{05E9}' | '\u{05DB}' | '\u{05DC}' | '\u{05D1}'
+                )
+            }) {
+                return letter.role == Role::Boundary;
+            }
+            if letter.first {
+                return true;
+            }
+        }
+        true
+    }
+
+    // the vowel of the letter is a qamats qatan
+    fn is_qamats_qatan(text: &str, letter: &Letter, vowel: char) -> bool {
+        if is_hbr_point_qamats_qatan(vowel) {
+            return true;
+        }
+        if !is_hbr_point_qamats(vowel) {
+            return false;
+        }
+        let cluster = &letter.cluster;
+        cluster.text.find('\u{05B8}').map_or(false, |offset| {
+            qamats_kind(text, cluster.range.start + offset) == Some(QamatsKind::Qatan)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_to_ktiv_male() {
+        // holam
+        assert_eq!(to_ktiv_male("קֹדֶשׁ"), "קודש");
+        assert_eq!(to_ktiv_male("שָׁלוֹם"), "שלום");
+        assert_eq!(to_ktiv_male("לֹא"), "לא");
+        assert_eq!(to_ktiv_male("רֹאשׁ"), "ראש");
+        assert_eq!(to_ktiv_male("פֹּה"), "פה");
+        // qubuts, qamats qatan, hataf qamats
+        assert_eq!(to_ktiv_male("קֻמִי"), "קומי");
+        assert_eq!(to_ktiv_male("כׇּל"), "כל");
+        assert_eq!(to_ktiv_male("תָּכְנִית"), "תוכנית");
+        assert_eq!(to_ktiv_male("אֳנִיָּה"), "אונייה");
+        assert_eq!(to_ktiv_male("צָהֳרַיִם"), "צוהריים");
+        // hiriq
+        assert_eq!(to_ktiv_male("דִּבֵּר"), "דיבר");
+        assert_eq!(to_ktiv_male("מִכְתָּב"), "מכתב");
+        assert_eq!(to_ktiv_male("עִיר"), "עיר");
+        assert_eq!(to_ktiv_male("מִבֵּית"), "מבית");
+        assert_eq!(to_ktiv_male("וּמִבֵּית"), "ומבית");
+        assert_eq!(to_ktiv_male("שֶׁמִּבֵּית"), "שמבית");
+        assert_eq!(to_ktiv_male("וְהַמִּתְנַדְּבִים"), "והמתנדבים");
+        assert_eq!(to_ktiv_male("לְדִבְרֵי"), "לדברי");
+        assert_eq!(to_ktiv_male("לְסִפּוּר"), "לסיפור");
+        assert_eq!(to_ktiv_male("בַּיִת"), "בית");
+        assert_eq!(to_ktiv_male("רִאשׁוֹן"), "ראשון");
+        assert_eq!(to_ktiv_male("\u{FB1D}"), "\u{05D9}");
+        // consonantal vav and yod
+        assert_eq!(to_ktiv_male("מִצְוָה"), "מצווה");
+        assert_eq!(to_ktiv_male("צִוִּיתִי"), "ציוויתי");
+        assert_eq!(to_ktiv_male("מִצְוֹת"), "מצוות");
+        assert_eq!(to_ktiv_male("עַוְלָה"), "עוולה");
+        assert_eq!(to_ktiv_male("שַׁוְא"), "שווא");
+        assert_eq!(to_ktiv_male("שָׁוְא"), "שווא");
+        assert_eq!(to_ktiv_male("מְצֻוֶּה"), "מצווה");
+        assert_eq!(to_ktiv_male("מְקֻוֶּה"), "מקווה");
+        assert_eq!(to_ktiv_male("קֻוְּתָה"), "קוותה");
+        assert_eq!(to_ktiv_male("עֵינַיִם"), "עיניים");
+        assert_eq!(to_ktiv_male("גּוֹיִם"), "גויים");
+        assert_eq!(to_ktiv_male("מַיִם"), "מים");
+        assert_eq!(to_ktiv_male("הַמַּיִם"), "המים");
+        assert_eq!(to_ktiv_male("וּמַיִם"), "ומים");
+        assert_eq!(to_ktiv_male("שָׁמַיִם"), "שמיים");
+        assert_eq!(to_ktiv_male("וְהָלַךְ"), "והלך");
+        assert_eq!(to_ktiv_male("סְיָג"), "סייג");
+        assert_eq!(to_ktiv_male("מְיֻחָד"), "מיוחד");
+        assert_eq!(to_ktiv_male("אֵלָיו"), "אליו");
+        // accents and punctuation
+        assert_eq!(to_ktiv_male("בְּרֵאשִׁ֖ית בָּרָ֣א"), "בראשית ברא");
+        assert_eq!(to_ktiv_male("hello"), "hello");
+    }
+}
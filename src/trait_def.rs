@@ -1,11 +1,26 @@
 //use crate::collections_api::unicode_block_alphabetic_presentation_form::*;
 //use crate::collections_api::unicode_block_hebrew::*;
 //use crate::collections_api::unicode_script_hebrew::*;
-//use crate::*;
+use crate::*;
 /// A trait for identification and validation of Hebrew characters
 ///
 /// For the implementation of the trait the functions descibed in de file 'collections_api.rs' are reused
 pub trait HebrewUnicodeScript {
+    /// Returns the character that is checked, `None` if there is none.
+    ///
+    /// The methods added after the first release have a default implementation that checks
+    /// this character, so implementors only need to provide it. The default returns `None`,
+    /// for which these methods return `false`.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert_eq!('א'.as_char(), Some('א'));
+    /// ```
+    fn as_char(&self) -> Option<char> {
+        None
+    }
+
     // source:: collections_api.rs
     // module:: Unicode Script Hebrew
 
@@ -57,6 +72,40 @@ pub trait HebrewUnicodeScript {
     /// assert!('\u{FB1F}'.is_script_hbr_ligature());
     /// ```
     fn is_script_hbr_ligature(&self) -> bool;
+    /// Checks if the given character belongs to the unicode script 'Hebrew' or is a Hebrew-adjacent character.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('₪'.is_script_hbr_extended());
+    /// ```
+    fn is_script_hbr_extended(&self) -> bool {
+        self.as_char().map_or(false, is_script_hbr_extended)
+    }
+
+    // source:: adjacent.rs
+    // module:: Hebrew Adjacent
+
+    /// Checks if the given character is used in Hebrew text but lies outside the two unicode blocks.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('\u{200F}'.is_hebrew_adjacent());
+    /// ```
+    fn is_hebrew_adjacent(&self) -> bool {
+        self.as_char().map_or(false, is_hebrew_adjacent)
+    }
+    /// Checks if the given character is one of the Hebrew letterlike symbols (U+2135..U+2138).
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('ℵ'.is_adj_letterlike_symbol());
+    /// ```
+    fn is_adj_letterlike_symbol(&self) -> bool {
+        self.as_char().map_or(false, is_adj_letterlike_symbol)
+    }
 
     // source:: collections_api.rs
     // module:: Unicode Block: Hebrew
@@ -69,6 +118,26 @@ pub trait HebrewUnicodeScript {
     /// assert!('ד'.is_hbr_block());
     /// ```
     fn is_hbr_block(&self) -> bool;
+    /// Checks if the given character lies in the range of the unicode block 'Hebrew', assigned or reserved.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('\u{05C8}'.is_hbr_block_range());
+    /// ```
+    fn is_hbr_block_range(&self) -> bool {
+        self.as_char().map_or(false, is_hbr_block_range)
+    }
+    /// Checks if the given character is reserved (unassigned) in the unicode block 'Hebrew'.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('\u{05C8}'.is_hbr_block_reserved());
+    /// ```
+    fn is_hbr_block_reserved(&self) -> bool {
+        self.as_char().map_or(false, is_hbr_block_reserved)
+    }
     /// Checks if the given character is a Hebrew acccent.
     /// # Example
     /// ```
@@ -169,6 +238,26 @@ pub trait HebrewUnicodeScript {
     /// assert!('\u{FB4E}'.is_apf_block());
     /// ```
     fn is_apf_block(&self) -> bool;
+    /// Checks if the given character lies in the Hebrew part of the unicode block 'Alphabetic Presentation Form', assigned or reserved.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('\u{FB37}'.is_apf_block_range());
+    /// ```
+    fn is_apf_block_range(&self) -> bool {
+        self.as_char().map_or(false, is_apf_block_range)
+    }
+    /// Checks if the given character is reserved (unassigned) in the Hebrew part of the unicode block 'Alphabetic Presentation Form'.
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::HebrewUnicodeScript;
+    ///
+    /// assert!('\u{FB37}'.is_apf_block_reserved());
+    /// ```
+    fn is_apf_block_reserved(&self) -> bool {
+        self.as_char().map_or(false, is_apf_block_reserved)
+    }
     /// Checks if the given character is an apf letter.
     /// # Example
     /// ```
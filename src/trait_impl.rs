@@ -5,6 +5,10 @@ use crate::*;
 /// A trait for identification and validation of Hebrew characters
 ///
 impl HebrewUnicodeScript for char {
+    fn as_char(&self) -> Option<char> {
+        Some(*self)
+    }
+
     // source:: collections_api.rs
     // module:: Unicode Script Hebrew
 
@@ -531,11 +535,28 @@ mod test_ {
     fn test_script_hebrew_ligature() {
         assert!(!'a'.is_script_hbr_ligature());
     }
+
+    #[test]
+    fn test_script_hebrew_extended() {
+        assert!(!'a'.is_script_hbr_extended());
+        assert!('ℵ'.is_script_hbr_extended());
+    }
+
+    #[test]
+    fn test_hebrew_adjacent() {
+        assert!(!'a'.is_hebrew_adjacent());
+        assert!(!'א'.is_adj_letterlike_symbol());
+    }
     //unicode block Hebrew (collections)
     #[test]
     fn test_hbr_block() {
         assert!(!'a'.is_hbr_block());
     }
+    #[test]
+    fn test_hbr_block_range() {
+        assert!(!'a'.is_hbr_block_range());
+        assert!(!'a'.is_hbr_block_reserved());
+    }
 
     #[test]
     fn test_hbr_accent() {
@@ -596,6 +617,11 @@ mod test_ {
     fn test_apf_block() {
         assert!(!'a'.is_apf_block());
     }
+    #[test]
+    fn test_apf_block_range() {
+        assert!(!'a'.is_apf_block_range());
+        assert!(!'a'.is_apf_block_reserved());
+    }
 
     #[test]
     fn test_apf_point_reading_sign() {
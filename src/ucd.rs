@@ -0,0 +1,561 @@
+// This file is generated by `cargo xtask ucd` from the files in the `ucd`
+// directory. Do not edit it by hand.
+
+pub mod unicode_data {
+    use crate::*;
+    use core::ops::RangeInclusive;
+
+    /// The version of the Unicode Character Database the tables are generated from.
+    pub const UCD_VERSION: UnicodeVersion = UnicodeVersion::new(14, 0);
+
+    /// The range of the unicode block 'Hebrew' (Blocks.txt).
+    pub const UCD_BLOCK_HBR: RangeInclusive<char> = '\u{0590}'..='\u{05FF}';
+
+    /// The range of the unicode block 'Alphabetic Presentation Forms' (Blocks.txt).
+    pub const UCD_BLOCK_APF: RangeInclusive<char> = '\u{FB00}'..='\u{FB4F}';
+
+    /// The Hebrew part of the unicode block 'Alphabetic Presentation Forms', from its
+    /// first Hebrew character to the end of the block.
+    pub const UCD_BLOCK_APF_HEBREW: RangeInclusive<char> = '\u{FB1D}'..='\u{FB4F}';
+
+    /// The characters with the property Script=Hebrew (Scripts.txt), as ranges in code
+    /// point order.
+    pub const UCD_SCRIPT_HBR: &[RangeInclusive<char>] = &[
+        '\u{0591}'..='\u{05C7}',
+        '\u{05D0}'..='\u{05EA}',
+        '\u{05EF}'..='\u{05F4}',
+        '\u{FB1D}'..='\u{FB36}',
+        '\u{FB38}'..='\u{FB3C}',
+        '\u{FB3E}'..='\u{FB3E}',
+        '\u{FB40}'..='\u{FB41}',
+        '\u{FB43}'..='\u{FB44}',
+        '\u{FB46}'..='\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_script_hbr`] returns `true`, in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_script_hbr, SCRIPT_HBR_CHARS};
+    ///
+    /// assert!(SCRIPT_HBR_CHARS.iter().all(|c| is_script_hbr(*c)));
+    /// ```
+    pub const SCRIPT_HBR_CHARS: [char; 134] = [
+        '\u{0591}', '\u{0592}', '\u{0593}', '\u{0594}', '\u{0595}', '\u{0596}', '\u{0597}',
+        '\u{0598}', '\u{0599}', '\u{059A}', '\u{059B}', '\u{059C}', '\u{059D}', '\u{059E}',
+        '\u{059F}', '\u{05A0}', '\u{05A1}', '\u{05A2}', '\u{05A3}', '\u{05A4}', '\u{05A5}',
+        '\u{05A6}', '\u{05A7}', '\u{05A8}', '\u{05A9}', '\u{05AA}', '\u{05AB}', '\u{05AC}',
+        '\u{05AD}', '\u{05AE}', '\u{05AF}', '\u{05B0}', '\u{05B1}', '\u{05B2}', '\u{05B3}',
+        '\u{05B4}', '\u{05B5}', '\u{05B6}', '\u{05B7}', '\u{05B8}', '\u{05B9}', '\u{05BA}',
+        '\u{05BB}', '\u{05BC}', '\u{05BD}', '\u{05BE}', '\u{05BF}', '\u{05C0}', '\u{05C1}',
+        '\u{05C2}', '\u{05C3}', '\u{05C4}', '\u{05C5}', '\u{05C6}', '\u{05C7}', '\u{05D0}',
+        '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}', '\u{05D7}',
+        '\u{05D8}', '\u{05D9}', '\u{05DA}', '\u{05DB}', '\u{05DC}', '\u{05DD}', '\u{05DE}',
+        '\u{05DF}', '\u{05E0}', '\u{05E1}', '\u{05E2}', '\u{05E3}', '\u{05E4}', '\u{05E5}',
+        '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}', '\u{05EA}', '\u{05EF}', '\u{05F0}',
+        '\u{05F1}', '\u{05F2}', '\u{05F3}', '\u{05F4}', '\u{FB1D}', '\u{FB1E}', '\u{FB1F}',
+        '\u{FB20}', '\u{FB21}', '\u{FB22}', '\u{FB23}', '\u{FB24}', '\u{FB25}', '\u{FB26}',
+        '\u{FB27}', '\u{FB28}', '\u{FB29}', '\u{FB2A}', '\u{FB2B}', '\u{FB2C}', '\u{FB2D}',
+        '\u{FB2E}', '\u{FB2F}', '\u{FB30}', '\u{FB31}', '\u{FB32}', '\u{FB33}', '\u{FB34}',
+        '\u{FB35}', '\u{FB36}', '\u{FB38}', '\u{FB39}', '\u{FB3A}', '\u{FB3B}', '\u{FB3C}',
+        '\u{FB3E}', '\u{FB40}', '\u{FB41}', '\u{FB43}', '\u{FB44}', '\u{FB46}', '\u{FB47}',
+        '\u{FB48}', '\u{FB49}', '\u{FB4A}', '\u{FB4B}', '\u{FB4C}', '\u{FB4D}', '\u{FB4E}',
+        '\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_script_hbr`] returns `true`, as ranges in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_script_hbr, SCRIPT_HBR_RANGES};
+    ///
+    /// assert!(SCRIPT_HBR_RANGES.iter().all(|range| range.clone().all(is_script_hbr)));
+    /// ```
+    pub const SCRIPT_HBR_RANGES: &[RangeInclusive<char>] = &[
+        '\u{0591}'..='\u{05C7}',
+        '\u{05D0}'..='\u{05EA}',
+        '\u{05EF}'..='\u{05F4}',
+        '\u{FB1D}'..='\u{FB36}',
+        '\u{FB38}'..='\u{FB3C}',
+        '\u{FB3E}'..='\u{FB3E}',
+        '\u{FB40}'..='\u{FB41}',
+        '\u{FB43}'..='\u{FB44}',
+        '\u{FB46}'..='\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_script_hbr_consonant`] returns `true`, in code point order.
+    pub const SCRIPT_HBR_CONSONANT_CHARS: [char; 36] = [
+        '\u{05D0}', '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}',
+        '\u{05D7}', '\u{05D8}', '\u{05D9}', '\u{05DA}', '\u{05DB}', '\u{05DC}', '\u{05DD}',
+        '\u{05DE}', '\u{05DF}', '\u{05E0}', '\u{05E1}', '\u{05E2}', '\u{05E3}', '\u{05E4}',
+        '\u{05E5}', '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}', '\u{05EA}', '\u{FB20}',
+        '\u{FB21}', '\u{FB22}', '\u{FB23}', '\u{FB24}', '\u{FB25}', '\u{FB26}', '\u{FB27}',
+        '\u{FB28}',
+    ];
+
+    /// The characters for which [`is_script_hbr_consonant`] returns `true`, as ranges in code point order.
+    pub const SCRIPT_HBR_CONSONANT_RANGES: &[RangeInclusive<char>] =
+        &['\u{05D0}'..='\u{05EA}', '\u{FB20}'..='\u{FB28}'];
+
+    /// The characters for which [`is_script_hbr_point`] returns `true`, in code point order.
+    pub const SCRIPT_HBR_POINT_CHARS: [char; 19] = [
+        '\u{05B0}', '\u{05B1}', '\u{05B2}', '\u{05B3}', '\u{05B4}', '\u{05B5}', '\u{05B6}',
+        '\u{05B7}', '\u{05B8}', '\u{05B9}', '\u{05BA}', '\u{05BB}', '\u{05BC}', '\u{05BD}',
+        '\u{05BF}', '\u{05C1}', '\u{05C2}', '\u{05C7}', '\u{FB1E}',
+    ];
+
+    /// The characters for which [`is_script_hbr_point`] returns `true`, as ranges in code point order.
+    pub const SCRIPT_HBR_POINT_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05B0}'..='\u{05BD}',
+        '\u{05BF}'..='\u{05BF}',
+        '\u{05C1}'..='\u{05C2}',
+        '\u{05C7}'..='\u{05C7}',
+        '\u{FB1E}'..='\u{FB1E}',
+    ];
+
+    /// The characters for which [`is_script_hbr_ligature`] returns `true`, in code point order.
+    pub const SCRIPT_HBR_LIGATURE_CHARS: [char; 5] =
+        ['\u{05F0}', '\u{05F1}', '\u{05F2}', '\u{FB1F}', '\u{FB4F}'];
+
+    /// The characters for which [`is_script_hbr_ligature`] returns `true`, as ranges in code point order.
+    pub const SCRIPT_HBR_LIGATURE_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05F0}'..='\u{05F2}',
+        '\u{FB1F}'..='\u{FB1F}',
+        '\u{FB4F}'..='\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_script_hbr_ligature_yiddisch`] returns `true`, in code point order.
+    pub const SCRIPT_HBR_LIGATURE_YIDDISCH_CHARS: [char; 4] =
+        ['\u{05F0}', '\u{05F1}', '\u{05F2}', '\u{FB1F}'];
+
+    /// The characters for which [`is_script_hbr_ligature_yiddisch`] returns `true`, as ranges in code point order.
+    pub const SCRIPT_HBR_LIGATURE_YIDDISCH_RANGES: &[RangeInclusive<char>] =
+        &['\u{05F0}'..='\u{05F2}', '\u{FB1F}'..='\u{FB1F}'];
+
+    /// The characters for which [`is_hbr_block`] returns `true`, in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_hbr_block, HBR_BLOCK_CHARS};
+    ///
+    /// assert!(HBR_BLOCK_CHARS.iter().all(|c| is_hbr_block(*c)));
+    /// ```
+    pub const HBR_BLOCK_CHARS: [char; 88] = [
+        '\u{0591}', '\u{0592}', '\u{0593}', '\u{0594}', '\u{0595}', '\u{0596}', '\u{0597}',
+        '\u{0598}', '\u{0599}', '\u{059A}', '\u{059B}', '\u{059C}', '\u{059D}', '\u{059E}',
+        '\u{059F}', '\u{05A0}', '\u{05A1}', '\u{05A2}', '\u{05A3}', '\u{05A4}', '\u{05A5}',
+        '\u{05A6}', '\u{05A7}', '\u{05A8}', '\u{05A9}', '\u{05AA}', '\u{05AB}', '\u{05AC}',
+        '\u{05AD}', '\u{05AE}', '\u{05AF}', '\u{05B0}', '\u{05B1}', '\u{05B2}', '\u{05B3}',
+        '\u{05B4}', '\u{05B5}', '\u{05B6}', '\u{05B7}', '\u{05B8}', '\u{05B9}', '\u{05BA}',
+        '\u{05BB}', '\u{05BC}', '\u{05BD}', '\u{05BE}', '\u{05BF}', '\u{05C0}', '\u{05C1}',
+        '\u{05C2}', '\u{05C3}', '\u{05C4}', '\u{05C5}', '\u{05C6}', '\u{05C7}', '\u{05D0}',
+        '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}', '\u{05D7}',
+        '\u{05D8}', '\u{05D9}', '\u{05DA}', '\u{05DB}', '\u{05DC}', '\u{05DD}', '\u{05DE}',
+        '\u{05DF}', '\u{05E0}', '\u{05E1}', '\u{05E2}', '\u{05E3}', '\u{05E4}', '\u{05E5}',
+        '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}', '\u{05EA}', '\u{05EF}', '\u{05F0}',
+        '\u{05F1}', '\u{05F2}', '\u{05F3}', '\u{05F4}',
+    ];
+
+    /// The characters for which [`is_hbr_block`] returns `true`, as ranges in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_hbr_block, HBR_BLOCK_RANGES};
+    ///
+    /// assert!(HBR_BLOCK_RANGES.iter().all(|range| range.clone().all(is_hbr_block)));
+    /// ```
+    pub const HBR_BLOCK_RANGES: &[RangeInclusive<char>] = &[
+        '\u{0591}'..='\u{05C7}',
+        '\u{05D0}'..='\u{05EA}',
+        '\u{05EF}'..='\u{05F4}',
+    ];
+
+    /// The characters for which [`is_hbr_accent`] returns `true`, in code point order.
+    pub const HBR_ACCENT_CHARS: [char; 30] = [
+        '\u{0591}', '\u{0592}', '\u{0593}', '\u{0594}', '\u{0595}', '\u{0596}', '\u{0597}',
+        '\u{0598}', '\u{0599}', '\u{059A}', '\u{059B}', '\u{059C}', '\u{059D}', '\u{059E}',
+        '\u{059F}', '\u{05A0}', '\u{05A1}', '\u{05A2}', '\u{05A3}', '\u{05A4}', '\u{05A5}',
+        '\u{05A6}', '\u{05A7}', '\u{05A8}', '\u{05A9}', '\u{05AA}', '\u{05AB}', '\u{05AC}',
+        '\u{05AD}', '\u{05AE}',
+    ];
+
+    /// The characters for which [`is_hbr_accent`] returns `true`, as ranges in code point order.
+    pub const HBR_ACCENT_RANGES: &[RangeInclusive<char>] = &['\u{0591}'..='\u{05AE}'];
+
+    /// The characters for which [`is_hbr_mark`] returns `true`, in code point order.
+    pub const HBR_MARK_CHARS: [char; 3] = ['\u{05AF}', '\u{05C4}', '\u{05C5}'];
+
+    /// The characters for which [`is_hbr_mark`] returns `true`, as ranges in code point order.
+    pub const HBR_MARK_RANGES: &[RangeInclusive<char>] =
+        &['\u{05AF}'..='\u{05AF}', '\u{05C4}'..='\u{05C5}'];
+
+    /// The characters for which [`is_hbr_point`] returns `true`, in code point order.
+    pub const HBR_POINT_CHARS: [char; 18] = [
+        '\u{05B0}', '\u{05B1}', '\u{05B2}', '\u{05B3}', '\u{05B4}', '\u{05B5}', '\u{05B6}',
+        '\u{05B7}', '\u{05B8}', '\u{05B9}', '\u{05BA}', '\u{05BB}', '\u{05BC}', '\u{05BD}',
+        '\u{05BF}', '\u{05C1}', '\u{05C2}', '\u{05C7}',
+    ];
+
+    /// The characters for which [`is_hbr_point`] returns `true`, as ranges in code point order.
+    pub const HBR_POINT_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05B0}'..='\u{05BD}',
+        '\u{05BF}'..='\u{05BF}',
+        '\u{05C1}'..='\u{05C2}',
+        '\u{05C7}'..='\u{05C7}',
+    ];
+
+    /// The characters for which [`is_hbr_punctuation`] returns `true`, in code point order.
+    pub const HBR_PUNCTUATION_CHARS: [char; 6] = [
+        '\u{05BE}', '\u{05C0}', '\u{05C3}', '\u{05C6}', '\u{05F3}', '\u{05F4}',
+    ];
+
+    /// The characters for which [`is_hbr_punctuation`] returns `true`, as ranges in code point order.
+    pub const HBR_PUNCTUATION_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05BE}'..='\u{05BE}',
+        '\u{05C0}'..='\u{05C0}',
+        '\u{05C3}'..='\u{05C3}',
+        '\u{05C6}'..='\u{05C6}',
+        '\u{05F3}'..='\u{05F4}',
+    ];
+
+    /// The characters for which [`is_hbr_consonant`] returns `true`, in code point order.
+    pub const HBR_CONSONANT_CHARS: [char; 27] = [
+        '\u{05D0}', '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}',
+        '\u{05D7}', '\u{05D8}', '\u{05D9}', '\u{05DA}', '\u{05DB}', '\u{05DC}', '\u{05DD}',
+        '\u{05DE}', '\u{05DF}', '\u{05E0}', '\u{05E1}', '\u{05E2}', '\u{05E3}', '\u{05E4}',
+        '\u{05E5}', '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}', '\u{05EA}',
+    ];
+
+    /// The characters for which [`is_hbr_consonant`] returns `true`, as ranges in code point order.
+    pub const HBR_CONSONANT_RANGES: &[RangeInclusive<char>] = &['\u{05D0}'..='\u{05EA}'];
+
+    /// The characters for which [`is_hbr_consonant_normal`] returns `true`, in code point order.
+    pub const HBR_CONSONANT_NORMAL_CHARS: [char; 22] = [
+        '\u{05D0}', '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}',
+        '\u{05D7}', '\u{05D8}', '\u{05D9}', '\u{05DB}', '\u{05DC}', '\u{05DE}', '\u{05E0}',
+        '\u{05E1}', '\u{05E2}', '\u{05E4}', '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}',
+        '\u{05EA}',
+    ];
+
+    /// The characters for which [`is_hbr_consonant_normal`] returns `true`, as ranges in code point order.
+    pub const HBR_CONSONANT_NORMAL_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05D0}'..='\u{05D9}',
+        '\u{05DB}'..='\u{05DC}',
+        '\u{05DE}'..='\u{05DE}',
+        '\u{05E0}'..='\u{05E2}',
+        '\u{05E4}'..='\u{05E4}',
+        '\u{05E6}'..='\u{05EA}',
+    ];
+
+    /// The characters for which [`is_hbr_consonant_final`] returns `true`, in code point order.
+    pub const HBR_CONSONANT_FINAL_CHARS: [char; 5] =
+        ['\u{05DA}', '\u{05DD}', '\u{05DF}', '\u{05E3}', '\u{05E5}'];
+
+    /// The characters for which [`is_hbr_consonant_final`] returns `true`, as ranges in code point order.
+    pub const HBR_CONSONANT_FINAL_RANGES: &[RangeInclusive<char>] = &[
+        '\u{05DA}'..='\u{05DA}',
+        '\u{05DD}'..='\u{05DD}',
+        '\u{05DF}'..='\u{05DF}',
+        '\u{05E3}'..='\u{05E3}',
+        '\u{05E5}'..='\u{05E5}',
+    ];
+
+    /// The characters for which [`is_hbr_ligature_yiddish`] returns `true`, in code point order.
+    pub const HBR_LIGATURE_YIDDISH_CHARS: [char; 3] = ['\u{05F0}', '\u{05F1}', '\u{05F2}'];
+
+    /// The characters for which [`is_hbr_ligature_yiddish`] returns `true`, as ranges in code point order.
+    pub const HBR_LIGATURE_YIDDISH_RANGES: &[RangeInclusive<char>] = &['\u{05F0}'..='\u{05F2}'];
+
+    /// The characters for which [`is_apf_block`] returns `true`, in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_apf_block, APF_BLOCK_CHARS};
+    ///
+    /// assert!(APF_BLOCK_CHARS.iter().all(|c| is_apf_block(*c)));
+    /// ```
+    pub const APF_BLOCK_CHARS: [char; 46] = [
+        '\u{FB1D}', '\u{FB1E}', '\u{FB1F}', '\u{FB20}', '\u{FB21}', '\u{FB22}', '\u{FB23}',
+        '\u{FB24}', '\u{FB25}', '\u{FB26}', '\u{FB27}', '\u{FB28}', '\u{FB29}', '\u{FB2A}',
+        '\u{FB2B}', '\u{FB2C}', '\u{FB2D}', '\u{FB2E}', '\u{FB2F}', '\u{FB30}', '\u{FB31}',
+        '\u{FB32}', '\u{FB33}', '\u{FB34}', '\u{FB35}', '\u{FB36}', '\u{FB38}', '\u{FB39}',
+        '\u{FB3A}', '\u{FB3B}', '\u{FB3C}', '\u{FB3E}', '\u{FB40}', '\u{FB41}', '\u{FB43}',
+        '\u{FB44}', '\u{FB46}', '\u{FB47}', '\u{FB48}', '\u{FB49}', '\u{FB4A}', '\u{FB4B}',
+        '\u{FB4C}', '\u{FB4D}', '\u{FB4E}', '\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_apf_block`] returns `true`, as ranges in code point order.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_apf_block, APF_BLOCK_RANGES};
+    ///
+    /// assert!(APF_BLOCK_RANGES.iter().all(|range| range.clone().all(is_apf_block)));
+    /// ```
+    pub const APF_BLOCK_RANGES: &[RangeInclusive<char>] = &[
+        '\u{FB1D}'..='\u{FB36}',
+        '\u{FB38}'..='\u{FB3C}',
+        '\u{FB3E}'..='\u{FB3E}',
+        '\u{FB40}'..='\u{FB41}',
+        '\u{FB43}'..='\u{FB44}',
+        '\u{FB46}'..='\u{FB4F}',
+    ];
+
+    /// The characters for which [`is_apf_consonant`] returns `true`, in code point order.
+    pub const APF_CONSONANT_CHARS: [char; 9] = [
+        '\u{FB20}', '\u{FB21}', '\u{FB22}', '\u{FB23}', '\u{FB24}', '\u{FB25}', '\u{FB26}',
+        '\u{FB27}', '\u{FB28}',
+    ];
+
+    /// The characters for which [`is_apf_consonant`] returns `true`, as ranges in code point order.
+    pub const APF_CONSONANT_RANGES: &[RangeInclusive<char>] = &['\u{FB20}'..='\u{FB28}'];
+
+    /// The characters for which [`is_apf_consonant_with_vowel`] returns `true`, in code point order.
+    pub const APF_CONSONANT_WITH_VOWEL_CHARS: [char; 33] = [
+        '\u{FB1D}', '\u{FB2A}', '\u{FB2B}', '\u{FB2C}', '\u{FB2D}', '\u{FB2E}', '\u{FB2F}',
+        '\u{FB30}', '\u{FB31}', '\u{FB32}', '\u{FB33}', '\u{FB34}', '\u{FB35}', '\u{FB36}',
+        '\u{FB38}', '\u{FB39}', '\u{FB3A}', '\u{FB3B}', '\u{FB3C}', '\u{FB3E}', '\u{FB40}',
+        '\u{FB41}', '\u{FB43}', '\u{FB44}', '\u{FB46}', '\u{FB47}', '\u{FB48}', '\u{FB49}',
+        '\u{FB4A}', '\u{FB4B}', '\u{FB4C}', '\u{FB4D}', '\u{FB4E}',
+    ];
+
+    /// The characters for which [`is_apf_consonant_with_vowel`] returns `true`, as ranges in code point order.
+    pub const APF_CONSONANT_WITH_VOWEL_RANGES: &[RangeInclusive<char>] = &[
+        '\u{FB1D}'..='\u{FB1D}',
+        '\u{FB2A}'..='\u{FB36}',
+        '\u{FB38}'..='\u{FB3C}',
+        '\u{FB3E}'..='\u{FB3E}',
+        '\u{FB40}'..='\u{FB41}',
+        '\u{FB43}'..='\u{FB44}',
+        '\u{FB46}'..='\u{FB4E}',
+    ];
+
+    /// The characters for which [`is_apf_point_reading_sign`] returns `true`, in code point order.
+    pub const APF_POINT_READING_SIGN_CHARS: [char; 1] = ['\u{FB1E}'];
+
+    /// The characters for which [`is_apf_point_reading_sign`] returns `true`, as ranges in code point order.
+    pub const APF_POINT_READING_SIGN_RANGES: &[RangeInclusive<char>] = &['\u{FB1E}'..='\u{FB1E}'];
+
+    /// The characters for which [`is_apf_ligature`] returns `true`, in code point order.
+    pub const APF_LIGATURE_CHARS: [char; 2] = ['\u{FB1F}', '\u{FB4F}'];
+
+    /// The characters for which [`is_apf_ligature`] returns `true`, as ranges in code point order.
+    pub const APF_LIGATURE_RANGES: &[RangeInclusive<char>] =
+        &['\u{FB1F}'..='\u{FB1F}', '\u{FB4F}'..='\u{FB4F}'];
+
+    /// The characters for which [`is_apf_alternative`] returns `true`, in code point order.
+    pub const APF_ALTERNATIVE_CHARS: [char; 2] = ['\u{FB20}', '\u{FB29}'];
+
+    /// The characters for which [`is_apf_alternative`] returns `true`, as ranges in code point order.
+    pub const APF_ALTERNATIVE_RANGES: &[RangeInclusive<char>] =
+        &['\u{FB20}'..='\u{FB20}', '\u{FB29}'..='\u{FB29}'];
+
+    /// The characters for which [`is_apf_consonant_wide`] returns `true`, in code point order.
+    pub const APF_CONSONANT_WIDE_CHARS: [char; 8] = [
+        '\u{FB21}', '\u{FB22}', '\u{FB23}', '\u{FB24}', '\u{FB25}', '\u{FB26}', '\u{FB27}',
+        '\u{FB28}',
+    ];
+
+    /// The characters for which [`is_apf_consonant_wide`] returns `true`, as ranges in code point order.
+    pub const APF_CONSONANT_WIDE_RANGES: &[RangeInclusive<char>] = &['\u{FB21}'..='\u{FB28}'];
+
+    /// The reserved (unassigned) code points of the unicode block 'Hebrew', as ranges in
+    /// code point order.
+    pub const HBR_BLOCK_RESERVED_RANGES: &[RangeInclusive<char>] = &[
+        '\u{0590}'..='\u{0590}',
+        '\u{05C8}'..='\u{05CF}',
+        '\u{05EB}'..='\u{05EE}',
+        '\u{05F5}'..='\u{05FF}',
+    ];
+
+    /// The reserved (unassigned) code points of the Hebrew part of the unicode block
+    /// 'Alphabetic Presentation Forms', as ranges in code point order.
+    pub const APF_BLOCK_RESERVED_RANGES: &[RangeInclusive<char>] = &[
+        '\u{FB37}'..='\u{FB37}',
+        '\u{FB3D}'..='\u{FB3D}',
+        '\u{FB3F}'..='\u{FB3F}',
+        '\u{FB42}'..='\u{FB42}',
+        '\u{FB45}'..='\u{FB45}',
+    ];
+
+    /// The names of the characters of UnicodeData.txt (the Hebrew script and the
+    /// Hebrew-adjacent characters), in code point order.
+    pub const UCD_NAMES: &[(char, &str)] = &[
+        ('\u{034F}', "COMBINING GRAPHEME JOINER"),
+        ('\u{0591}', "HEBREW ACCENT ETNAHTA"),
+        ('\u{0592}', "HEBREW ACCENT SEGOL"),
+        ('\u{0593}', "HEBREW ACCENT SHALSHELET"),
+        ('\u{0594}', "HEBREW ACCENT ZAQEF QATAN"),
+        ('\u{0595}', "HEBREW ACCENT ZAQEF GADOL"),
+        ('\u{0596}', "HEBREW ACCENT TIPEHA"),
+        ('\u{0597}', "HEBREW ACCENT REVIA"),
+        ('\u{0598}', "HEBREW ACCENT ZARQA"),
+        ('\u{0599}', "HEBREW ACCENT PASHTA"),
+        ('\u{059A}', "HEBREW ACCENT YETIV"),
+        ('\u{059B}', "HEBREW ACCENT TEVIR"),
+        ('\u{059C}', "HEBREW ACCENT GERESH"),
+        ('\u{059D}', "HEBREW ACCENT GERESH MUQDAM"),
+        ('\u{059E}', "HEBREW ACCENT GERSHAYIM"),
+        ('\u{059F}', "HEBREW ACCENT QARNEY PARA"),
+        ('\u{05A0}', "HEBREW ACCENT TELISHA GEDOLA"),
+        ('\u{05A1}', "HEBREW ACCENT PAZER"),
+        ('\u{05A2}', "HEBREW ACCENT ATNAH HAFUKH"),
+        ('\u{05A3}', "HEBREW ACCENT MUNAH"),
+        ('\u{05A4}', "HEBREW ACCENT MAHAPAKH"),
+        ('\u{05A5}', "HEBREW ACCENT MERKHA"),
+        ('\u{05A6}', "HEBREW ACCENT MERKHA KEFULA"),
+        ('\u{05A7}', "HEBREW ACCENT DARGA"),
+        ('\u{05A8}', "HEBREW ACCENT QADMA"),
+        ('\u{05A9}', "HEBREW ACCENT TELISHA QETANA"),
+        ('\u{05AA}', "HEBREW ACCENT YERAH BEN YOMO"),
+        ('\u{05AB}', "HEBREW ACCENT OLE"),
+        ('\u{05AC}', "HEBREW ACCENT ILUY"),
+        ('\u{05AD}', "HEBREW ACCENT DEHI"),
+        ('\u{05AE}', "HEBREW ACCENT ZINOR"),
+        ('\u{05AF}', "HEBREW MARK MASORA CIRCLE"),
+        ('\u{05B0}', "HEBREW POINT SHEVA"),
+        ('\u{05B1}', "HEBREW POINT HATAF SEGOL"),
+        ('\u{05B2}', "HEBREW POINT HATAF PATAH"),
+        ('\u{05B3}', "HEBREW POINT HATAF QAMATS"),
+        ('\u{05B4}', "HEBREW POINT HIRIQ"),
+        ('\u{05B5}', "HEBREW POINT TSERE"),
+        ('\u{05B6}', "HEBREW POINT SEGOL"),
+        ('\u{05B7}', "HEBREW POINT PATAH"),
+        ('\u{05B8}', "HEBREW POINT QAMATS"),
+        ('\u{05B9}', "HEBREW POINT HOLAM"),
+        ('\u{05BA}', "HEBREW POINT HOLAM HASER FOR VAV"),
+        ('\u{05BB}', "HEBREW POINT QUBUTS"),
+        ('\u{05BC}', "HEBREW POINT DAGESH OR MAPIQ"),
+        ('\u{05BD}', "HEBREW POINT METEG"),
+        ('\u{05BE}', "HEBREW PUNCTUATION MAQAF"),
+        ('\u{05BF}', "HEBREW POINT RAFE"),
+        ('\u{05C0}', "HEBREW PUNCTUATION PASEQ"),
+        ('\u{05C1}', "HEBREW POINT SHIN DOT"),
+        ('\u{05C2}', "HEBREW POINT SIN DOT"),
+        ('\u{05C3}', "HEBREW PUNCTUATION SOF PASUQ"),
+        ('\u{05C4}', "HEBREW MARK UPPER DOT"),
+        ('\u{05C5}', "HEBREW MARK LOWER DOT"),
+        ('\u{05C6}', "HEBREW PUNCTUATION NUN HAFUKHA"),
+        ('\u{05C7}', "HEBREW POINT QAMATS QATAN"),
+        ('\u{05D0}', "HEBREW LETTER ALEF"),
+        ('\u{05D1}', "HEBREW LETTER BET"),
+        ('\u{05D2}', "HEBREW LETTER GIMEL"),
+        ('\u{05D3}', "HEBREW LETTER DALET"),
+        ('\u{05D4}', "HEBREW LETTER HE"),
+        ('\u{05D5}', "HEBREW LETTER VAV"),
+        ('\u{05D6}', "HEBREW LETTER ZAYIN"),
+        ('\u{05D7}', "HEBREW LETTER HET"),
+        ('\u{05D8}', "HEBREW LETTER TET"),
+        ('\u{05D9}', "HEBREW LETTER YOD"),
+        ('\u{05DA}', "HEBREW LETTER FINAL KAF"),
+        ('\u{05DB}', "HEBREW LETTER KAF"),
+        ('\u{05DC}', "HEBREW LETTER LAMED"),
+        ('\u{05DD}', "HEBREW LETTER FINAL MEM"),
+        ('\u{05DE}', "HEBREW LETTER MEM"),
+        ('\u{05DF}', "HEBREW LETTER FINAL NUN"),
+        ('\u{05E0}', "HEBREW LETTER NUN"),
+        ('\u{05E1}', "HEBREW LETTER SAMEKH"),
+        ('\u{05E2}', "HEBREW LETTER AYIN"),
+        ('\u{05E3}', "HEBREW LETTER FINAL PE"),
+        ('\u{05E4}', "HEBREW LETTER PE"),
+        ('\u{05E5}', "HEBREW LETTER FINAL TSADI"),
+        ('\u{05E6}', "HEBREW LETTER TSADI"),
+        ('\u{05E7}', "HEBREW LETTER QOF"),
+        ('\u{05E8}', "HEBREW LETTER RESH"),
+        ('\u{05E9}', "HEBREW LETTER SHIN"),
+        ('\u{05EA}', "HEBREW LETTER TAV"),
+        ('\u{05EF}', "HEBREW YOD TRIANGLE"),
+        ('\u{05F0}', "HEBREW LIGATURE YIDDISH DOUBLE VAV"),
+        ('\u{05F1}', "HEBREW LIGATURE YIDDISH VAV YOD"),
+        ('\u{05F2}', "HEBREW LIGATURE YIDDISH DOUBLE YOD"),
+        ('\u{05F3}', "HEBREW PUNCTUATION GERESH"),
+        ('\u{05F4}', "HEBREW PUNCTUATION GERSHAYIM"),
+        ('\u{200F}', "RIGHT-TO-LEFT MARK"),
+        ('\u{20AA}', "NEW SHEQEL SIGN"),
+        ('\u{2135}', "ALEF SYMBOL"),
+        ('\u{2136}', "BET SYMBOL"),
+        ('\u{2137}', "GIMEL SYMBOL"),
+        ('\u{2138}', "DALET SYMBOL"),
+        ('\u{25CC}', "DOTTED CIRCLE"),
+        ('\u{FB1D}', "HEBREW LETTER YOD WITH HIRIQ"),
+        ('\u{FB1E}', "HEBREW POINT JUDEO-SPANISH VARIKA"),
+        ('\u{FB1F}', "HEBREW LIGATURE YIDDISH YOD YOD PATAH"),
+        ('\u{FB20}', "HEBREW LETTER ALTERNATIVE AYIN"),
+        ('\u{FB21}', "HEBREW LETTER WIDE ALEF"),
+        ('\u{FB22}', "HEBREW LETTER WIDE DALET"),
+        ('\u{FB23}', "HEBREW LETTER WIDE HE"),
+        ('\u{FB24}', "HEBREW LETTER WIDE KAF"),
+        ('\u{FB25}', "HEBREW LETTER WIDE LAMED"),
+        ('\u{FB26}', "HEBREW LETTER WIDE FINAL MEM"),
+        ('\u{FB27}', "HEBREW LETTER WIDE RESH"),
+        ('\u{FB28}', "HEBREW LETTER WIDE TAV"),
+        ('\u{FB29}', "HEBREW LETTER ALTERNATIVE PLUS SIGN"),
+        ('\u{FB2A}', "HEBREW LETTER SHIN WITH SHIN DOT"),
+        ('\u{FB2B}', "HEBREW LETTER SHIN WITH SIN DOT"),
+        ('\u{FB2C}', "HEBREW LETTER SHIN WITH DAGESH AND SHIN DOT"),
+        ('\u{FB2D}', "HEBREW LETTER SHIN WITH DAGESH AND SIN DOT"),
+        ('\u{FB2E}', "HEBREW LETTER ALEF WITH PATAH"),
+        ('\u{FB2F}', "HEBREW LETTER ALEF WITH QAMATS"),
+        ('\u{FB30}', "HEBREW LETTER ALEF WITH MAPIQ"),
+        ('\u{FB31}', "HEBREW LETTER BET WITH DAGESH"),
+        ('\u{FB32}', "HEBREW LETTER GIMEL WITH DAGESH"),
+        ('\u{FB33}', "HEBREW LETTER DALET WITH DAGESH"),
+        ('\u{FB34}', "HEBREW LETTER HE WITH MAPIQ"),
+        ('\u{FB35}', "HEBREW LETTER VAV WITH DAGESH"),
+        ('\u{FB36}', "HEBREW LETTER ZAYIN WITH DAGESH"),
+        ('\u{FB38}', "HEBREW LETTER TET WITH DAGESH"),
+        ('\u{FB39}', "HEBREW LETTER YOD WITH DAGESH"),
+        ('\u{FB3A}', "HEBREW LETTER FINAL KAF WITH DAGESH"),
+        ('\u{FB3B}', "HEBREW LETTER KAF WITH DAGESH"),
+        ('\u{FB3C}', "HEBREW LETTER LAMED WITH DAGESH"),
+        ('\u{FB3E}', "HEBREW LETTER MEM WITH DAGESH"),
+        ('\u{FB40}', "HEBREW LETTER NUN WITH DAGESH"),
+        ('\u{FB41}', "HEBREW LETTER SAMEKH WITH DAGESH"),
+        ('\u{FB43}', "HEBREW LETTER FINAL PE WITH DAGESH"),
+        ('\u{FB44}', "HEBREW LETTER PE WITH DAGESH"),
+        ('\u{FB46}', "HEBREW LETTER TSADI WITH DAGESH"),
+        ('\u{FB47}', "HEBREW LETTER QOF WITH DAGESH"),
+        ('\u{FB48}', "HEBREW LETTER RESH WITH DAGESH"),
+        ('\u{FB49}', "HEBREW LETTER SHIN WITH DAGESH"),
+        ('\u{FB4A}', "HEBREW LETTER TAV WITH DAGESH"),
+        ('\u{FB4B}', "HEBREW LETTER VAV WITH HOLAM"),
+        ('\u{FB4C}', "HEBREW LETTER BET WITH RAFE"),
+        ('\u{FB4D}', "HEBREW LETTER KAF WITH RAFE"),
+        ('\u{FB4E}', "HEBREW LETTER PE WITH RAFE"),
+        ('\u{FB4F}', "HEBREW LIGATURE ALEF LAMED"),
+    ];
+
+    // checks if the character lies in one of the sorted ranges
+    pub(crate) const fn in_ranges(c: char, ranges: &[RangeInclusive<char>]) -> bool {
+        let mut index = 0;
+        while index < ranges.len() {
+            let range = &ranges[index];
+            if c < *range.start() {
+                return false;
+            }
+            if c <= *range.end() {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    /// Returns the name of a character of the Hebrew script or of a Hebrew-adjacent
+    /// character, as listed in UnicodeData.txt.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::unicode_name;
+    ///
+    /// assert_eq!(unicode_name('א'), Some("HEBREW LETTER ALEF"));
+    /// assert_eq!(unicode_name('₪'), Some("NEW SHEQEL SIGN"));
+    /// assert_eq!(unicode_name('a'), None);
+    /// ```
+    pub fn unicode_name(c: char) -> Option<&'static str> {
+        UCD_NAMES
+            .binary_search_by_key(&c, |(member, _)| *member)
+            .ok()
+            .map(|index| UCD_NAMES[index].1)
+    }
+}
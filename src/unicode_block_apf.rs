@@ -8,7 +8,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬡ';
     /// assert!(is_apf_consonant_wide_alef(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_alef(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_alef(c: char) -> bool {
         // U+FB21
         matches!(c, '\u{FB21}')
     }
@@ -21,7 +21,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬢ';
     /// assert!(is_apf_consonant_wide_dalet(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_dalet(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_dalet(c: char) -> bool {
         // U+FB22
         matches!(c, '\u{FB22}')
     }
@@ -34,7 +34,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬣ';
     /// assert!(is_apf_consonant_wide_he(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_he(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_he(c: char) -> bool {
         // U+FB23
         matches!(c, '\u{FB23}')
     }
@@ -47,7 +47,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬤ';
     /// assert!(is_apf_consonant_wide_kaf(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_kaf(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_kaf(c: char) -> bool {
         // U+FB24
         matches!(c, '\u{FB24}')
     }
@@ -60,7 +60,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬥ';
     /// assert!(is_apf_consonant_wide_lamed(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_lamed(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_lamed(c: char) -> bool {
         // U+FB25
         matches!(c, '\u{FB25}')
     }
@@ -73,7 +73,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬦ';
     /// assert!(is_apf_consonant_wide_final_mem(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_final_mem(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_final_mem(c: char) -> bool {
         // U+FB26
         matches!(c, '\u{FB26}')
     }
@@ -86,7 +86,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬧ';
     /// assert!(is_apf_consonant_wide_resh(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_resh(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_resh(c: char) -> bool {
         // U+FB27
         matches!(c, '\u{FB27}')
     }
@@ -99,7 +99,7 @@ pub mod apf_consonant_wide {
     /// let consonant = 'ﬨ';
     /// assert!(is_apf_consonant_wide_tav(consonant));
     /// ```
-    pub fn is_apf_consonant_wide_tav(c: char) -> bool {
+    pub const fn is_apf_consonant_wide_tav(c: char) -> bool {
         // U+FB28
         matches!(c, '\u{FB28}')
     }
@@ -114,7 +114,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'יִ';
     /// assert!(is_apf_consonant_vowel_yod_hiriq(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_yod_hiriq(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_yod_hiriq(c: char) -> bool {
         // U+FB1D
         matches!(c, '\u{FB1D}')
     }
@@ -127,7 +127,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'שׁ';
     /// assert!(is_apf_consonant_vowel_shin_shindot(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_shin_shindot(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_shin_shindot(c: char) -> bool {
         // U+FB2A
         matches!(c, '\u{FB2A}')
     }
@@ -140,7 +140,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'שׂ';
     /// assert!(is_apf_consonant_vowel_shin_sindot(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_shin_sindot(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_shin_sindot(c: char) -> bool {
         // U+FB2B
         matches!(c, '\u{FB2B}')
     }
@@ -153,7 +153,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'שּׁ';
     /// assert!(is_apf_consonant_vowel_shin_dagesh_shindot(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_shin_dagesh_shindot(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_shin_dagesh_shindot(c: char) -> bool {
         // U+FB2C
         matches!(c, '\u{FB2C}')
     }
@@ -166,7 +166,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'שּׂ';
     /// assert!(is_apf_consonant_vowel_shin_dagesh_sindot(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_shin_dagesh_sindot(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_shin_dagesh_sindot(c: char) -> bool {
         // U+FB2D
         matches!(c, '\u{FB2D}')
     }
@@ -179,7 +179,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'אַ';
     /// assert!(is_apf_consonant_vowel_alef_patah(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_alef_patah(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_alef_patah(c: char) -> bool {
         // U+FB2E
         matches!(c, '\u{FB2E}')
     }
@@ -192,7 +192,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'אָ';
     /// assert!(is_apf_consonant_vowel_alef_qamats(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_alef_qamats(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_alef_qamats(c: char) -> bool {
         // U+FB2F
         matches!(c, '\u{FB2F}')
     }
@@ -205,7 +205,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'אּ';
     /// assert!(is_apf_consonant_vowel_alef_mapiq(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_alef_mapiq(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_alef_mapiq(c: char) -> bool {
         // U+FB30
         matches!(c, '\u{FB30}')
     }
@@ -218,7 +218,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'בּ';
     /// assert!(is_apf_consonant_vowel_bet_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_bet_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_bet_dagesh(c: char) -> bool {
         // U+FB31
         matches!(c, '\u{FB31}')
     }
@@ -231,7 +231,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'גּ';
     /// assert!(is_apf_consonant_vowel_gimmel_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_gimmel_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_gimmel_dagesh(c: char) -> bool {
         // U+FB32
         matches!(c, '\u{FB32}')
     }
@@ -244,7 +244,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'דּ';
     /// assert!(is_apf_consonant_vowel_dalet_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_dalet_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_dalet_dagesh(c: char) -> bool {
         // U+FB33
         matches!(c, '\u{FB33}')
     }
@@ -257,7 +257,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'הּ';
     /// assert!(is_apf_consonant_vowel_he_mapiq(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_he_mapiq(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_he_mapiq(c: char) -> bool {
         // U+FB34
         matches!(c, '\u{FB34}')
     }
@@ -270,7 +270,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'וּ';
     /// assert!(is_apf_consonant_vowel_vav_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_vav_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_vav_dagesh(c: char) -> bool {
         // U+FB35
         matches!(c, '\u{FB35}')
     }
@@ -283,7 +283,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'זּ';
     /// assert!(is_apf_consonant_vowel_zayin_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_zayin_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_zayin_dagesh(c: char) -> bool {
         // U+FB36
         matches!(c, '\u{FB36}')
     }
@@ -296,7 +296,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'טּ';
     /// assert!(is_apf_consonant_vowel_tet_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_tet_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_tet_dagesh(c: char) -> bool {
         // U+FB38
         matches!(c, '\u{FB38}')
     }
@@ -309,7 +309,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'יּ';
     /// assert!(is_apf_consonant_vowel_yod_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_yod_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_yod_dagesh(c: char) -> bool {
         // U+FB39
         matches!(c, '\u{FB39}')
     }
@@ -322,7 +322,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'ךּ';
     /// assert!(is_apf_consonant_vowel_final_kaf_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_final_kaf_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_final_kaf_dagesh(c: char) -> bool {
         // U+FB3A
         matches!(c, '\u{FB3A}')
     }
@@ -335,7 +335,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'כּ';
     /// assert!(is_apf_consonant_vowel_kaf_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_kaf_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_kaf_dagesh(c: char) -> bool {
         // U+FB3B
         matches!(c, '\u{FB3B}')
     }
@@ -349,7 +349,7 @@ pub mod apf_consonant_with_vowel {
     /// assert!(is_apf_consonant_vowel_lamed_dagesh(consonant));
     ///
     /// ```
-    pub fn is_apf_consonant_vowel_lamed_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_lamed_dagesh(c: char) -> bool {
         // U+FB3C
         matches!(c, '\u{FB3C}')
     }
@@ -362,7 +362,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'מּ';
     /// assert!(is_apf_consonant_vowel_mem_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_mem_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_mem_dagesh(c: char) -> bool {
         // U+FB3E
         matches!(c, '\u{FB3E}')
     }
@@ -375,7 +375,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'נּ';
     /// assert!(is_apf_consonant_vowel_nun_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_nun_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_nun_dagesh(c: char) -> bool {
         // U+FB40
         matches!(c, '\u{FB40}')
     }
@@ -388,7 +388,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'סּ';
     /// assert!(is_apf_consonant_vowel_samekh_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_samekh_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_samekh_dagesh(c: char) -> bool {
         // U+FB41
         matches!(c, '\u{FB41}')
     }
@@ -401,7 +401,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'ףּ';
     /// assert!(is_apf_consonant_vowel_final_pe_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_final_pe_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_final_pe_dagesh(c: char) -> bool {
         // U+FB43
         matches!(c, '\u{FB43}')
     }
@@ -414,7 +414,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'פּ';
     /// assert!(is_apf_consonant_vowel_pe_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_pe_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_pe_dagesh(c: char) -> bool {
         // U+FB44
         matches!(c, '\u{FB44}')
     }
@@ -427,7 +427,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'צּ';
     /// assert!(is_apf_consonant_vowel_tsadi_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_tsadi_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_tsadi_dagesh(c: char) -> bool {
         // U+FB46
         matches!(c, '\u{FB46}')
     }
@@ -440,7 +440,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'קּ';
     /// assert!(is_apf_consonant_vowel_qof_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_qof_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_qof_dagesh(c: char) -> bool {
         // U+FB47
         matches!(c, '\u{FB47}')
     }
@@ -453,7 +453,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'רּ';
     /// assert!(is_apf_consonant_vowel_resh_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_resh_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_resh_dagesh(c: char) -> bool {
         // U+FB48
         matches!(c, '\u{FB48}')
     }
@@ -466,7 +466,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'שּ';
     /// assert!(is_apf_consonant_vowel_shin_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_shin_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_shin_dagesh(c: char) -> bool {
         // U+FB49
         matches!(c, '\u{FB49}')
     }
@@ -479,7 +479,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'תּ';
     /// assert!(is_apf_consonant_vowel_tav_dagesh(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_tav_dagesh(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_tav_dagesh(c: char) -> bool {
         // U+FB4A
         matches!(c, '\u{FB4A}')
     }
@@ -492,7 +492,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'וֹ';
     /// assert!(is_apf_consonant_vowel_vav_holam(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_vav_holam(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_vav_holam(c: char) -> bool {
         // U+FB4B
         matches!(c, '\u{FB4B}')
     }
@@ -505,7 +505,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'בֿ';
     /// assert!(is_apf_consonant_vowel_bet_rafe(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_bet_rafe(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_bet_rafe(c: char) -> bool {
         // U+FB4C
         matches!(c, '\u{FB4C}')
     }
@@ -518,7 +518,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'כֿ';
     /// assert!(is_apf_consonant_vowel_kaf_rafe(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_kaf_rafe(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_kaf_rafe(c: char) -> bool {
         // U+FB4D
         matches!(c, '\u{FB4D}')
     }
@@ -531,7 +531,7 @@ pub mod apf_consonant_with_vowel {
     /// let consonant = 'פֿ';
     /// assert!(is_apf_consonant_vowel_pe_rafe(consonant));
     /// ```
-    pub fn is_apf_consonant_vowel_pe_rafe(c: char) -> bool {
+    pub const fn is_apf_consonant_vowel_pe_rafe(c: char) -> bool {
         // U+FB4E
         matches!(c, '\u{FB4E}')
     }
@@ -546,7 +546,7 @@ pub mod apf_letter_alternative {
     /// let consonant = 'ﬠ';
     /// assert!(is_apf_consonant_alternative_ayin(consonant));
     /// ```
-    pub fn is_apf_consonant_alternative_ayin(c: char) -> bool {
+    pub const fn is_apf_consonant_alternative_ayin(c: char) -> bool {
         // U+FB20
         matches!(c, '\u{FB20}')
     }
@@ -559,7 +559,7 @@ pub mod apf_letter_alternative {
     /// let consonant = '﬩';
     /// assert!(is_apf_letter_alternative_plus_sign(consonant));
     /// ```
-    pub fn is_apf_letter_alternative_plus_sign(c: char) -> bool {
+    pub const fn is_apf_letter_alternative_plus_sign(c: char) -> bool {
         // U+FB29
         matches!(c, '\u{FB29}')
     }
@@ -574,7 +574,7 @@ pub mod apf_ligature {
     /// let consonant = 'ײַ';
     /// assert!(is_apf_ligature_yiddisch_yod_yod_patah(consonant));
     /// ```
-    pub fn is_apf_ligature_yiddisch_yod_yod_patah(c: char) -> bool {
+    pub const fn is_apf_ligature_yiddisch_yod_yod_patah(c: char) -> bool {
         // U+FB1F
         matches!(c, '\u{FB1F}')
     }
@@ -587,7 +587,7 @@ pub mod apf_ligature {
     /// let consonant = 'ﭏ';
     /// assert!(is_apf_ligature_alef_lamed(consonant));
     /// ```
-    pub fn is_apf_ligature_alef_lamed(c: char) -> bool {
+    pub const fn is_apf_ligature_alef_lamed(c: char) -> bool {
         // U+FB4F
         matches!(c, '\u{FB4F}')
     }
@@ -602,7 +602,7 @@ pub mod apf_point {
     /// let consonant = 'ﬞ';
     /// assert!(is_apf_point_judeo_spanish_varika(consonant));
     /// ```
-    pub fn is_apf_point_judeo_spanish_varika(c: char) -> bool {
+    pub const fn is_apf_point_judeo_spanish_varika(c: char) -> bool {
         // U+FB1E
         matches!(c, '\u{FB1E}')
     }
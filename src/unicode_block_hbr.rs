@@ -8,7 +8,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'א';
     /// assert!(is_hbr_consonant_alef(consonant));
     /// ```
-    pub fn is_hbr_consonant_alef(c: char) -> bool {
+    pub const fn is_hbr_consonant_alef(c: char) -> bool {
         // U+05D0
         matches!(c, '\u{05D0}')
     }
@@ -21,7 +21,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ב';
     /// assert!(is_hbr_consonant_bet(consonant));
     /// ```
-    pub fn is_hbr_consonant_bet(c: char) -> bool {
+    pub const fn is_hbr_consonant_bet(c: char) -> bool {
         // U+05D1
         matches!(c, '\u{05D1}')
     }
@@ -34,7 +34,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ג';
     /// assert!(is_hbr_consonant_gimel(consonant));
     /// ```
-    pub fn is_hbr_consonant_gimel(c: char) -> bool {
+    pub const fn is_hbr_consonant_gimel(c: char) -> bool {
         // U+05D2
         matches!(c, '\u{05D2}')
     }
@@ -47,7 +47,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ד';
     /// assert!(is_hbr_consonant_dalet(consonant));
     /// ```
-    pub fn is_hbr_consonant_dalet(c: char) -> bool {
+    pub const fn is_hbr_consonant_dalet(c: char) -> bool {
         // U+05D3
         matches!(c, '\u{05D3}')
     }
@@ -60,7 +60,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ה';
     /// assert!(is_hbr_consonant_he(consonant));
     /// ```
-    pub fn is_hbr_consonant_he(c: char) -> bool {
+    pub const fn is_hbr_consonant_he(c: char) -> bool {
         // U+05D4
         matches!(c, '\u{05D4}')
     }
@@ -73,7 +73,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ו';
     /// assert!(is_hbr_consonant_vav(consonant));
     /// ```
-    pub fn is_hbr_consonant_vav(c: char) -> bool {
+    pub const fn is_hbr_consonant_vav(c: char) -> bool {
         // U+U+05D5
         matches!(c, '\u{05D5}')
     }
@@ -86,7 +86,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ז';
     /// assert!(is_hbr_consonant_zayin(consonant));
     /// ```
-    pub fn is_hbr_consonant_zayin(c: char) -> bool {
+    pub const fn is_hbr_consonant_zayin(c: char) -> bool {
         // U+05D6
         matches!(c, '\u{05D6}')
     }
@@ -99,7 +99,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ח';
     /// assert!(is_hbr_consonant_het(consonant));
     /// ```
-    pub fn is_hbr_consonant_het(c: char) -> bool {
+    pub const fn is_hbr_consonant_het(c: char) -> bool {
         // U+05D7
         matches!(c, '\u{05D7}')
     }
@@ -112,7 +112,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ט';
     /// assert!(is_hbr_consonant_tet(consonant));
     /// ```
-    pub fn is_hbr_consonant_tet(c: char) -> bool {
+    pub const fn is_hbr_consonant_tet(c: char) -> bool {
         // U+05D8
         matches!(c, '\u{05D8}')
     }
@@ -125,7 +125,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'י';
     /// assert!(is_hbr_consonant_yod(consonant));
     /// ```
-    pub fn is_hbr_consonant_yod(c: char) -> bool {
+    pub const fn is_hbr_consonant_yod(c: char) -> bool {
         // U+05D9
         matches!(c, '\u{05D9}')
     }
@@ -138,7 +138,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ך';
     /// assert!(is_hbr_consonant_final_kaf(consonant));
     /// ```
-    pub fn is_hbr_consonant_final_kaf(c: char) -> bool {
+    pub const fn is_hbr_consonant_final_kaf(c: char) -> bool {
         // U+05DA
         matches!(c, '\u{05DA}')
     }
@@ -151,7 +151,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'כ';
     /// assert!(is_hbr_consonant_kaf(consonant));
     /// ```
-    pub fn is_hbr_consonant_kaf(c: char) -> bool {
+    pub const fn is_hbr_consonant_kaf(c: char) -> bool {
         // U+05DB
         matches!(c, '\u{05DB}')
     }
@@ -164,7 +164,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ל';
     /// assert!(is_hbr_consonant_lamed(consonant));
     /// ```
-    pub fn is_hbr_consonant_lamed(c: char) -> bool {
+    pub const fn is_hbr_consonant_lamed(c: char) -> bool {
         // U+05DC
         matches!(c, '\u{05DC}')
     }
@@ -177,7 +177,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ם';
     /// assert!(is_hbr_consonant_final_mem(consonant));
     /// ```
-    pub fn is_hbr_consonant_final_mem(c: char) -> bool {
+    pub const fn is_hbr_consonant_final_mem(c: char) -> bool {
         // U+05DD
         matches!(c, '\u{05DD}')
     }
@@ -190,7 +190,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'מ';
     /// assert!(is_hbr_consonant_mem(consonant));
     /// ```
-    pub fn is_hbr_consonant_mem(c: char) -> bool {
+    pub const fn is_hbr_consonant_mem(c: char) -> bool {
         // U+05DE
         matches!(c, '\u{05DE}')
     }
@@ -203,7 +203,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ן';
     /// assert!(is_hbr_consonant_final_nun(consonant));
     /// ```
-    pub fn is_hbr_consonant_final_nun(c: char) -> bool {
+    pub const fn is_hbr_consonant_final_nun(c: char) -> bool {
         // U+05DF
         matches!(c, '\u{05DF}')
     }
@@ -216,7 +216,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'נ';
     /// assert!(is_hbr_consonant_nun(consonant));
     /// ```
-    pub fn is_hbr_consonant_nun(c: char) -> bool {
+    pub const fn is_hbr_consonant_nun(c: char) -> bool {
         // U+05E0
         matches!(c, '\u{05E0}')
     }
@@ -229,7 +229,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ס';
     /// assert!(is_hbr_consonant_samekh(consonant));
     /// ```
-    pub fn is_hbr_consonant_samekh(c: char) -> bool {
+    pub const fn is_hbr_consonant_samekh(c: char) -> bool {
         // U+05E1
         matches!(c, '\u{05E1}')
     }
@@ -242,7 +242,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ע';
     /// assert!(is_hbr_consonant_ayin(consonant));
     /// ```
-    pub fn is_hbr_consonant_ayin(c: char) -> bool {
+    pub const fn is_hbr_consonant_ayin(c: char) -> bool {
         // U+05E2
         matches!(c, '\u{05E2}')
     }
@@ -255,7 +255,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ף';
     /// assert!(is_hbr_consonant_final_pe(consonant));
     /// ```
-    pub fn is_hbr_consonant_final_pe(c: char) -> bool {
+    pub const fn is_hbr_consonant_final_pe(c: char) -> bool {
         // U+05E3
         matches!(c, '\u{05E3}')
     }
@@ -268,7 +268,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'פ';
     /// assert!(is_hbr_consonant_pe(consonant));
     /// ```
-    pub fn is_hbr_consonant_pe(c: char) -> bool {
+    pub const fn is_hbr_consonant_pe(c: char) -> bool {
         // U+05E4
         matches!(c, '\u{05E4}')
     }
@@ -281,7 +281,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ץ';
     /// assert!(is_hbr_consonant_final_tsadi(consonant));
     /// ```
-    pub fn is_hbr_consonant_final_tsadi(c: char) -> bool {
+    pub const fn is_hbr_consonant_final_tsadi(c: char) -> bool {
         // U+05E5
         matches!(c, '\u{05E5}')
     }
@@ -294,7 +294,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'צ';
     /// assert!(is_hbr_consonant_tsadi(consonant));
     /// ```
-    pub fn is_hbr_consonant_tsadi(c: char) -> bool {
+    pub const fn is_hbr_consonant_tsadi(c: char) -> bool {
         // U+05E6
         matches!(c, '\u{05E6}')
     }
@@ -307,7 +307,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ק';
     /// assert!(is_hbr_consonant_qof(consonant));
     /// ```
-    pub fn is_hbr_consonant_qof(c: char) -> bool {
+    pub const fn is_hbr_consonant_qof(c: char) -> bool {
         // U+05E7
         matches!(c, '\u{05E7}')
     }
@@ -320,7 +320,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ר';
     /// assert!(is_hbr_consonant_resh(consonant));
     /// ```
-    pub fn is_hbr_consonant_resh(c: char) -> bool {
+    pub const fn is_hbr_consonant_resh(c: char) -> bool {
         // U+05E8
         matches!(c, '\u{05E8}')
     }
@@ -333,7 +333,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ש';
     /// assert!(is_hbr_consonant_shin(consonant));
     /// ```
-    pub fn is_hbr_consonant_shin(c: char) -> bool {
+    pub const fn is_hbr_consonant_shin(c: char) -> bool {
         // U+05E9
         matches!(c, '\u{05E9}')
     }
@@ -346,7 +346,7 @@ pub mod hebrew_consonants {
     /// let consonant = 'ת';
     /// assert!(is_hbr_consonant_tav(consonant));
     /// ```
-    pub fn is_hbr_consonant_tav(c: char) -> bool {
+    pub const fn is_hbr_consonant_tav(c: char) -> bool {
         // U+05EA
         matches!(c, '\u{05EA}')
     }
@@ -361,7 +361,7 @@ pub mod hebrew_points {
     /// let point = 'ְ';
     /// assert!(is_hbr_point_sheva(point));
     /// ```
-    pub fn is_hbr_point_sheva(c: char) -> bool {
+    pub const fn is_hbr_point_sheva(c: char) -> bool {
         // U+05B0
         matches!(c, '\u{05B0}')
     }
@@ -374,7 +374,7 @@ pub mod hebrew_points {
     /// let point = 'ֱ';
     /// assert!(is_hbr_point_hataf_segol(point));
     /// ```
-    pub fn is_hbr_point_hataf_segol(c: char) -> bool {
+    pub const fn is_hbr_point_hataf_segol(c: char) -> bool {
         // U+05B1
         matches!(c, '\u{05B1}')
     }
@@ -387,7 +387,7 @@ pub mod hebrew_points {
     /// let point = 'ֲ';
     /// assert!(is_hbr_point_hataf_patah(point));
     /// ```
-    pub fn is_hbr_point_hataf_patah(c: char) -> bool {
+    pub const fn is_hbr_point_hataf_patah(c: char) -> bool {
         // U+05B2
         matches!(c, '\u{05B2}')
     }
@@ -400,7 +400,7 @@ pub mod hebrew_points {
     /// let point = 'ֳ';
     /// assert!(is_hbr_point_hataf_qamats(point));
     /// ```
-    pub fn is_hbr_point_hataf_qamats(c: char) -> bool {
+    pub const fn is_hbr_point_hataf_qamats(c: char) -> bool {
         // U+05B3
         matches!(c, '\u{05B3}')
     }
@@ -413,7 +413,7 @@ pub mod hebrew_points {
     /// let point = 'ִ';
     /// assert!(is_hbr_point_hiriq(point));
     /// ```
-    pub fn is_hbr_point_hiriq(c: char) -> bool {
+    pub const fn is_hbr_point_hiriq(c: char) -> bool {
         // U+05B4
         matches!(c, '\u{05B4}')
     }
@@ -426,7 +426,7 @@ pub mod hebrew_points {
     /// let point = 'ֵ';
     /// assert!(is_hbr_point_tsere(point));
     /// ```
-    pub fn is_hbr_point_tsere(c: char) -> bool {
+    pub const fn is_hbr_point_tsere(c: char) -> bool {
         // U+05B5
         matches!(c, '\u{05B5}')
     }
@@ -439,7 +439,7 @@ pub mod hebrew_points {
     /// let point = 'ֶ';
     /// assert!(is_hbr_point_segol(point));
     /// ```
-    pub fn is_hbr_point_segol(c: char) -> bool {
+    pub const fn is_hbr_point_segol(c: char) -> bool {
         // U+05B6
         matches!(c, '\u{05B6}')
     }
@@ -451,7 +451,7 @@ pub mod hebrew_points {
     /// let point = 'ַ';
     /// assert!(is_hbr_point_patah(point));
     /// ```
-    pub fn is_hbr_point_patah(c: char) -> bool {
+    pub const fn is_hbr_point_patah(c: char) -> bool {
         // U+05B7
         matches!(c, '\u{05B7}')
     }
@@ -464,7 +464,7 @@ pub mod hebrew_points {
     /// let point = 'ָ';
     /// assert!(is_hbr_point_qamats(point));
     /// ```
-    pub fn is_hbr_point_qamats(c: char) -> bool {
+    pub const fn is_hbr_point_qamats(c: char) -> bool {
         // U+05B8
         matches!(c, '\u{05B8}')
     }
@@ -477,7 +477,7 @@ pub mod hebrew_points {
     /// let point = 'ֹ';
     /// assert!(is_hbr_point_holam(point));
     /// ```
-    pub fn is_hbr_point_holam(c: char) -> bool {
+    pub const fn is_hbr_point_holam(c: char) -> bool {
         // U+05B9
         matches!(c, '\u{05B9}')
     }
@@ -490,7 +490,7 @@ pub mod hebrew_points {
     /// let point = 'ֺ';
     /// assert!(is_hbr_point_holam_haser_for_vav(point));
     /// ```
-    pub fn is_hbr_point_holam_haser_for_vav(c: char) -> bool {
+    pub const fn is_hbr_point_holam_haser_for_vav(c: char) -> bool {
         // U+05BA
         matches!(c, '\u{05BA}')
     }
@@ -503,7 +503,7 @@ pub mod hebrew_points {
     /// let point = 'ֻ';
     /// assert!(is_hbr_point_qubuts(point));
     /// ```
-    pub fn is_hbr_point_qubuts(c: char) -> bool {
+    pub const fn is_hbr_point_qubuts(c: char) -> bool {
         // U+05BB
         matches!(c, '\u{05BB}')
     }
@@ -516,7 +516,7 @@ pub mod hebrew_points {
     /// let point = 'ּ';
     /// assert!(is_hbr_point_dagesh_or_mapiq(point));
     /// ```
-    pub fn is_hbr_point_dagesh_or_mapiq(c: char) -> bool {
+    pub const fn is_hbr_point_dagesh_or_mapiq(c: char) -> bool {
         // U+05BC
         matches!(c, '\u{05BC}')
     }
@@ -529,7 +529,7 @@ pub mod hebrew_points {
     /// let point = 'ֽ';
     /// assert!(is_hbr_point_meteg(point));
     /// ```
-    pub fn is_hbr_point_meteg(c: char) -> bool {
+    pub const fn is_hbr_point_meteg(c: char) -> bool {
         // U+05BD
         matches!(c, '\u{05BD}')
     }
@@ -542,7 +542,7 @@ pub mod hebrew_points {
     /// let point = 'ֿ';
     /// assert!(is_hbr_point_rafe(point));
     /// ```
-    pub fn is_hbr_point_rafe(c: char) -> bool {
+    pub const fn is_hbr_point_rafe(c: char) -> bool {
         // U+05BF
         matches!(c, '\u{05BF}')
     }
@@ -555,7 +555,7 @@ pub mod hebrew_points {
     /// let point = 'ׁ';
     /// assert!(is_hbr_point_shin_dot(point));
     /// ```
-    pub fn is_hbr_point_shin_dot(c: char) -> bool {
+    pub const fn is_hbr_point_shin_dot(c: char) -> bool {
         // U+05C1
         matches!(c, '\u{05C1}')
     }
@@ -568,7 +568,7 @@ pub mod hebrew_points {
     /// let point = 'ׂ';
     /// assert!(is_hbr_point_sin_dot(point));
     /// ```
-    pub fn is_hbr_point_sin_dot(c: char) -> bool {
+    pub const fn is_hbr_point_sin_dot(c: char) -> bool {
         // U+05C2
         matches!(c, '\u{05C2}')
     }
@@ -581,7 +581,7 @@ pub mod hebrew_points {
     /// let point = 'ׇ';
     /// assert!(is_hbr_point_qamats_qatan(point));
     /// ```
-    pub fn is_hbr_point_qamats_qatan(c: char) -> bool {
+    pub const fn is_hbr_point_qamats_qatan(c: char) -> bool {
         // U+05C7
         matches!(c, '\u{05C7}')
     }
@@ -597,7 +597,7 @@ pub mod hebrew_accents {
     /// let accent = '֑';
     /// assert!(is_hbr_accent_etnahta(accent));
     /// ```
-    pub fn is_hbr_accent_etnahta(c: char) -> bool {
+    pub const fn is_hbr_accent_etnahta(c: char) -> bool {
         // U+0591
         matches!(c, '\u{0591}')
     }
@@ -610,7 +610,7 @@ pub mod hebrew_accents {
     /// let accent = '֒';
     /// assert!(is_hbr_accent_segol(accent));
     /// ```
-    pub fn is_hbr_accent_segol(c: char) -> bool {
+    pub const fn is_hbr_accent_segol(c: char) -> bool {
         // U+0592
         matches!(c, '\u{0592}')
     }
@@ -623,7 +623,7 @@ pub mod hebrew_accents {
     /// let accent = '֓';
     /// assert!(is_hbr_accent_shalshelet(accent));
     /// ```
-    pub fn is_hbr_accent_shalshelet(c: char) -> bool {
+    pub const fn is_hbr_accent_shalshelet(c: char) -> bool {
         // U+0593
         matches!(c, '\u{0593}')
     }
@@ -636,7 +636,7 @@ pub mod hebrew_accents {
     /// let accent = '֔';
     /// assert!(is_hbr_accent_zaqef_qatan(accent));
     /// ```
-    pub fn is_hbr_accent_zaqef_qatan(c: char) -> bool {
+    pub const fn is_hbr_accent_zaqef_qatan(c: char) -> bool {
         // U+0594
         matches!(c, '\u{0594}')
     }
@@ -649,7 +649,7 @@ pub mod hebrew_accents {
     /// let accent = '֕';
     /// assert!(is_hbr_accent_zaqef_gadol(accent));
     /// ```
-    pub fn is_hbr_accent_zaqef_gadol(c: char) -> bool {
+    pub const fn is_hbr_accent_zaqef_gadol(c: char) -> bool {
         // U+0595
         matches!(c, '\u{0595}')
     }
@@ -662,7 +662,7 @@ pub mod hebrew_accents {
     /// let accent = '֖';
     /// assert!(is_hbr_accent_tipeha(accent));
     /// ```
-    pub fn is_hbr_accent_tipeha(c: char) -> bool {
+    pub const fn is_hbr_accent_tipeha(c: char) -> bool {
         // U+0596
         matches!(c, '\u{0596}')
     }
@@ -675,7 +675,7 @@ pub mod hebrew_accents {
     /// let accent = '֗';
     /// assert!(is_hbr_accent_revia(accent));
     /// ```
-    pub fn is_hbr_accent_revia(c: char) -> bool {
+    pub const fn is_hbr_accent_revia(c: char) -> bool {
         // U+0597
         matches!(c, '\u{0597}')
     }
@@ -688,7 +688,7 @@ pub mod hebrew_accents {
     /// let accent = '֘';
     /// assert!(is_hbr_accent_zarqa(accent));
     /// ```
-    pub fn is_hbr_accent_zarqa(c: char) -> bool {
+    pub const fn is_hbr_accent_zarqa(c: char) -> bool {
         // U+0598
         matches!(c, '\u{0598}')
     }
@@ -701,7 +701,7 @@ pub mod hebrew_accents {
     /// let accent = '֙';
     /// assert!(is_hbr_accent_pashta(accent));
     /// ```
-    pub fn is_hbr_accent_pashta(c: char) -> bool {
+    pub const fn is_hbr_accent_pashta(c: char) -> bool {
         // U+0599
         matches!(c, '\u{0599}')
     }
@@ -714,7 +714,7 @@ pub mod hebrew_accents {
     /// let accent = '֚';
     /// assert!(is_hbr_accent_yetiv(accent));
     /// ```
-    pub fn is_hbr_accent_yetiv(c: char) -> bool {
+    pub const fn is_hbr_accent_yetiv(c: char) -> bool {
         // U+059A
         matches!(c, '\u{059A}')
     }
@@ -727,7 +727,7 @@ pub mod hebrew_accents {
     /// let accent = '֛';
     /// assert!(is_hbr_accent_tevir(accent));
     /// ```
-    pub fn is_hbr_accent_tevir(c: char) -> bool {
+    pub const fn is_hbr_accent_tevir(c: char) -> bool {
         // U+059B
         matches!(c, '\u{059B}')
     }
@@ -740,7 +740,7 @@ pub mod hebrew_accents {
     /// let accent = '֜';
     /// assert!(is_hbr_accent_geresh(accent));
     /// ```
-    pub fn is_hbr_accent_geresh(c: char) -> bool {
+    pub const fn is_hbr_accent_geresh(c: char) -> bool {
         // U+059C
         matches!(c, '\u{059C}')
     }
@@ -753,7 +753,7 @@ pub mod hebrew_accents {
     /// let accent = '֝';
     /// assert!(is_hbr_accent_geresh_muqdam(accent));
     /// ```
-    pub fn is_hbr_accent_geresh_muqdam(c: char) -> bool {
+    pub const fn is_hbr_accent_geresh_muqdam(c: char) -> bool {
         // U+059D
         matches!(c, '\u{059D}')
     }
@@ -766,7 +766,7 @@ pub mod hebrew_accents {
     /// let accent = '֞';
     /// assert!(is_hbr_accent_gershayim(accent));
     /// ```
-    pub fn is_hbr_accent_gershayim(c: char) -> bool {
+    pub const fn is_hbr_accent_gershayim(c: char) -> bool {
         // U+059E
         matches!(c, '\u{059E}')
     }
@@ -779,7 +779,7 @@ pub mod hebrew_accents {
     /// let accent = '֟';
     /// assert!(is_hbr_accent_qarney_para(accent));
     /// ```
-    pub fn is_hbr_accent_qarney_para(c: char) -> bool {
+    pub const fn is_hbr_accent_qarney_para(c: char) -> bool {
         // U+059F
         matches!(c, '\u{059F}')
     }
@@ -792,7 +792,7 @@ pub mod hebrew_accents {
     /// let accent = '֠';
     /// assert!(is_hbr_accent_telisha_gedola(accent));
     /// ```
-    pub fn is_hbr_accent_telisha_gedola(c: char) -> bool {
+    pub const fn is_hbr_accent_telisha_gedola(c: char) -> bool {
         // U+05A0
         matches!(c, '\u{05A0}')
     }
@@ -805,7 +805,7 @@ pub mod hebrew_accents {
     /// let accent = '֡';
     /// assert!(is_hbr_accent_pazer(accent));
     /// ```
-    pub fn is_hbr_accent_pazer(c: char) -> bool {
+    pub const fn is_hbr_accent_pazer(c: char) -> bool {
         // U+05A1
         matches!(c, '\u{05A1}')
     }
@@ -818,7 +818,7 @@ pub mod hebrew_accents {
     /// let accent = '֢';
     /// assert!(is_hbr_accent_atnah_hafukh(accent));
     /// ```
-    pub fn is_hbr_accent_atnah_hafukh(c: char) -> bool {
+    pub const fn is_hbr_accent_atnah_hafukh(c: char) -> bool {
         // U+05A2
         matches!(c, '\u{05A2}')
     }
@@ -831,7 +831,7 @@ pub mod hebrew_accents {
     /// let accent = '֣';
     /// assert!(is_hbr_accent_munah(accent));
     /// ```
-    pub fn is_hbr_accent_munah(c: char) -> bool {
+    pub const fn is_hbr_accent_munah(c: char) -> bool {
         // U+05A3
         matches!(c, '\u{05A3}')
     }
@@ -844,7 +844,7 @@ pub mod hebrew_accents {
     /// let accent = '֤';
     /// assert!(is_hbr_accent_mahapakh(accent));
     /// ```
-    pub fn is_hbr_accent_mahapakh(c: char) -> bool {
+    pub const fn is_hbr_accent_mahapakh(c: char) -> bool {
         // U+05A4
         matches!(c, '\u{05A4}')
     }
@@ -857,7 +857,7 @@ pub mod hebrew_accents {
     /// let accent = '֥';
     /// assert!(is_hbr_accent_merkha(accent));
     /// ```
-    pub fn is_hbr_accent_merkha(c: char) -> bool {
+    pub const fn is_hbr_accent_merkha(c: char) -> bool {
         // U+05A5
         matches!(c, '\u{05A5}')
     }
@@ -870,7 +870,7 @@ pub mod hebrew_accents {
     /// let accent = '֦';
     /// assert!(is_hbr_accent_merkha_kefula(accent));
     /// ```
-    pub fn is_hbr_accent_merkha_kefula(c: char) -> bool {
+    pub const fn is_hbr_accent_merkha_kefula(c: char) -> bool {
         // U+05A6
         matches!(c, '\u{05A6}')
     }
@@ -883,7 +883,7 @@ pub mod hebrew_accents {
     /// let accent = '֧';
     /// assert!(is_hbr_accent_darga(accent));
     /// ```
-    pub fn is_hbr_accent_darga(c: char) -> bool {
+    pub const fn is_hbr_accent_darga(c: char) -> bool {
         // U+05A7
         matches!(c, '\u{05A7}')
     }
@@ -896,7 +896,7 @@ pub mod hebrew_accents {
     /// let accent = '֨';
     /// assert!(is_hbr_accent_qadma(accent));
     /// ```
-    pub fn is_hbr_accent_qadma(c: char) -> bool {
+    pub const fn is_hbr_accent_qadma(c: char) -> bool {
         // U+05A8
         matches!(c, '\u{05A8}')
     }
@@ -909,7 +909,7 @@ pub mod hebrew_accents {
     /// let accent = '֩';
     /// assert!(is_hbr_accent_telisha_qetana(accent));
     /// ```
-    pub fn is_hbr_accent_telisha_qetana(c: char) -> bool {
+    pub const fn is_hbr_accent_telisha_qetana(c: char) -> bool {
         // U+05A9
         matches!(c, '\u{05A9}')
     }
@@ -922,7 +922,7 @@ pub mod hebrew_accents {
     /// let accent = '֪';
     /// assert!(is_hbr_accent_yerah_ben_yomo(accent));
     /// ```
-    pub fn is_hbr_accent_yerah_ben_yomo(c: char) -> bool {
+    pub const fn is_hbr_accent_yerah_ben_yomo(c: char) -> bool {
         // U+05AA
         matches!(c, '\u{05AA}')
     }
@@ -935,7 +935,7 @@ pub mod hebrew_accents {
     /// let accent = '֫';
     /// assert!(is_hbr_accent_ole(accent));
     /// ```
-    pub fn is_hbr_accent_ole(c: char) -> bool {
+    pub const fn is_hbr_accent_ole(c: char) -> bool {
         // U+05AB
         matches!(c, '\u{05AB}')
     }
@@ -948,7 +948,7 @@ pub mod hebrew_accents {
     /// let accent = '֬';
     /// assert!(is_hbr_accent_iluy(accent));
     /// ```
-    pub fn is_hbr_accent_iluy(c: char) -> bool {
+    pub const fn is_hbr_accent_iluy(c: char) -> bool {
         // U+05AC
         matches!(c, '\u{05AC}')
     }
@@ -961,7 +961,7 @@ pub mod hebrew_accents {
     /// let accent = '֭';
     /// assert!(is_hbr_accent_dehi(accent));
     /// ```
-    pub fn is_hbr_accent_dehi(c: char) -> bool {
+    pub const fn is_hbr_accent_dehi(c: char) -> bool {
         // U+05AD
         matches!(c, '\u{05AD}')
     }
@@ -974,7 +974,7 @@ pub mod hebrew_accents {
     /// let accent = '֮';
     /// assert!(is_hbr_accent_zinor(accent));
     /// ```
-    pub fn is_hbr_accent_zinor(c: char) -> bool {
+    pub const fn is_hbr_accent_zinor(c: char) -> bool {
         // U+05AE
         matches!(c, '\u{05AE}')
     }
@@ -989,7 +989,7 @@ pub mod hebrew_marks {
     /// let mark = '֯';
     /// assert!(is_hbr_mark_masora_circle(mark));
     /// ```
-    pub fn is_hbr_mark_masora_circle(c: char) -> bool {
+    pub const fn is_hbr_mark_masora_circle(c: char) -> bool {
         // U+05AF
         matches!(c, '\u{05AF}')
     }
@@ -1002,7 +1002,7 @@ pub mod hebrew_marks {
     /// let punctuation = 'ׄ';
     /// assert!(is_hbr_mark_upper_dot(punctuation));
     /// ```
-    pub fn is_hbr_mark_upper_dot(c: char) -> bool {
+    pub const fn is_hbr_mark_upper_dot(c: char) -> bool {
         // U+05C4
         matches!(c, '\u{05C4}')
     }
@@ -1015,7 +1015,7 @@ pub mod hebrew_marks {
     /// let mark = 'ׅ';
     /// assert!(is_hbr_mark_lower_dot(mark));
     /// ```
-    pub fn is_hbr_mark_lower_dot(c: char) -> bool {
+    pub const fn is_hbr_mark_lower_dot(c: char) -> bool {
         // U+05C5
         matches!(c, '\u{05C5}')
     }
@@ -1030,7 +1030,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '־';
     /// assert!(is_hbr_punctuation_maqaf(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_maqaf(c: char) -> bool {
+    pub const fn is_hbr_punctuation_maqaf(c: char) -> bool {
         // U+05BE
         matches!(c, '\u{05BE}')
     }
@@ -1043,7 +1043,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '׀';
     /// assert!(is_hbr_punctuation_paseq(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_paseq(c: char) -> bool {
+    pub const fn is_hbr_punctuation_paseq(c: char) -> bool {
         // U+05C0
         matches!(c, '\u{05C0}')
     }
@@ -1056,7 +1056,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '׃';
     /// assert!(is_hbr_punctuation_sof_pasuq(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_sof_pasuq(c: char) -> bool {
+    pub const fn is_hbr_punctuation_sof_pasuq(c: char) -> bool {
         // U+05C3
         matches!(c, '\u{05C3}')
     }
@@ -1069,7 +1069,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '׆';
     /// assert!(is_hbr_punctuation_nun_hafukha(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_nun_hafukha(c: char) -> bool {
+    pub const fn is_hbr_punctuation_nun_hafukha(c: char) -> bool {
         // U+05C6
         matches!(c, '\u{05C6}')
     }
@@ -1082,7 +1082,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '׳';
     /// assert!(is_hbr_punctuation_geresh(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_geresh(c: char) -> bool {
+    pub const fn is_hbr_punctuation_geresh(c: char) -> bool {
         // U+05F3
         matches!(c, '\u{05F3}')
     }
@@ -1095,7 +1095,7 @@ pub mod hebrew_punctuations {
     /// let punctuation = '״';
     /// assert!(is_hbr_punctuation_gershayim(punctuation));
     /// ```
-    pub fn is_hbr_punctuation_gershayim(c: char) -> bool {
+    pub const fn is_hbr_punctuation_gershayim(c: char) -> bool {
         // U+05F4
         matches!(c, '\u{05F4}')
     }
@@ -1110,7 +1110,7 @@ pub mod hebrew_misc {
     /// let ligature_yiddish = '\u{05EF}';
     /// assert!(is_hbr_yod_triangle(ligature_yiddish));
     /// ```
-    pub fn is_hbr_yod_triangle(c: char) -> bool {
+    pub const fn is_hbr_yod_triangle(c: char) -> bool {
         // U+05EF
         matches!(c, '\u{05EF}')
     }
@@ -1123,7 +1123,7 @@ pub mod hebrew_misc {
     /// let ligature_yiddish = 'װ';
     /// assert!(is_hbr_ligature_yiddisch_double_vav(ligature_yiddish));
     /// ```
-    pub fn is_hbr_ligature_yiddisch_double_vav(c: char) -> bool {
+    pub const fn is_hbr_ligature_yiddisch_double_vav(c: char) -> bool {
         // U+05F0
         matches!(c, '\u{05F0}')
     }
@@ -1136,7 +1136,7 @@ pub mod hebrew_misc {
     /// let ligature_yiddish = 'ױ';
     /// assert!(is_hbr_ligature_yiddisch_vav_yod(ligature_yiddish));
     /// ```
-    pub fn is_hbr_ligature_yiddisch_vav_yod(c: char) -> bool {
+    pub const fn is_hbr_ligature_yiddisch_vav_yod(c: char) -> bool {
         // U+05F1
         matches!(c, '\u{05F1}')
     }
@@ -1149,7 +1149,7 @@ pub mod hebrew_misc {
     /// let ligature_yiddish = 'ײ';
     /// assert!(is_hbr_ligature_yiddisch_double_yod(ligature_yiddish));
     /// ```
-    pub fn is_hbr_ligature_yiddisch_double_yod(c: char) -> bool {
+    pub const fn is_hbr_ligature_yiddisch_double_yod(c: char) -> bool {
         // U+05F2
         matches!(c, '\u{05F2}')
     }
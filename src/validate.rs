@@ -0,0 +1,674 @@
+pub mod text_issues {
+    use crate::phonology::letters::is_letter;
+    use crate::*;
+    use core::fmt;
+    use core::ops::Range;
+
+    /// The severity of an issue.
+    ///
+    /// Severities are ordered, `Severity::Warning < Severity::Error`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Severity {
+        /// The sequence is unusual but may be intended (e.g. a dagesh in a resh).
+        Warning,
+        /// The sequence is malformed.
+        Error,
+    }
+
+    /// The kind of an issue found by [`validate`].
+    ///
+    /// Every kind has a stable code (see [`IssueKind::code`]) that does not change between
+    /// releases; new kinds get new codes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum IssueKind {
+        /// A mark (point or accent) without a base letter (`HEB001`).
+        OrphanMark,
+        /// A second vowel (or sheva) on the same letter (`HEB002`).
+        ///
+        /// This is a warning: a few words are written with two vowels, e.g. the Masoretic
+        /// יְרוּשָׁלַ͏ִם. A vowel after a combining grapheme joiner (U+034F), the encoding that
+        /// the Unicode Standard recommends for such words, is not reported.
+        MultipleVowels,
+        /// A shin dot or sin dot on a letter other than shin (`HEB003`).
+        ShinDotOnNonShin,
+        /// A dagesh in alef, het, ayin, resh or a he that is not word-final (`HEB004`).
+        DageshOnGuttural,
+        /// A holam haser for vav (U+05BA) on a letter other than vav (`HEB005`).
+        HolamHaserOnNonVav,
+        /// The same mark twice on one letter (`HEB006`).
+        DuplicateMark,
+        /// A reserved (unassigned) code point of the Hebrew blocks (`HEB007`).
+        ReservedCodePoint,
+    }
+
+    impl IssueKind {
+        /// Returns the stable code of the issue kind.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::IssueKind;
+        ///
+        /// assert_eq!(IssueKind::ShinDotOnNonShin.code(), "HEB003");
+        /// ```
+        pub fn code(self) -> &'static str {
+            match self {
+                IssueKind::OrphanMark => "HEB001",
+                IssueKind::MultipleVowels => "HEB002",
+                IssueKind::ShinDotOnNonShin => "HEB003",
+                IssueKind::DageshOnGuttural => "HEB004",
+                IssueKind::HolamHaserOnNonVav => "HEB005",
+                IssueKind::DuplicateMark => "HEB006",
+                IssueKind::ReservedCodePoint => "HEB007",
+            }
+        }
+        /// Returns the severity of the issue kind.
+        pub fn severity(self) -> Severity {
+            match self {
+                IssueKind::MultipleVowels
+                | IssueKind::DageshOnGuttural
+                | IssueKind::DuplicateMark => Severity::Warning,
+                _ => Severity::Error,
+            }
+        }
+    }
+
+    impl fmt::Display for IssueKind {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let description = match self {
+                IssueKind::OrphanMark => "mark without a base letter",
+                IssueKind::MultipleVowels => "more than one vowel on a letter",
+                IssueKind::ShinDotOnNonShin => "shin or sin dot on a letter other than shin",
+                IssueKind::DageshOnGuttural => "dagesh in a guttural letter",
+                IssueKind::HolamHaserOnNonVav => "holam haser for vav on a letter other than vav",
+                IssueKind::DuplicateMark => "the same mark twice on a letter",
+                IssueKind::ReservedCodePoint => "reserved code point of the Hebrew blocks",
+            };
+            f.write_str(description)
+        }
+    }
+
+    /// A suggested fix for an issue, applied to [`HebrewTextIssue::byte_range`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Suggestion {
+        /// Remove the mark.
+        Remove,
+        /// Replace the mark by the given character.
+        Replace(char),
+    }
+
+    /// An issue found in a pointed text.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct HebrewTextIssue {
+        /// The kind of the issue.
+        pub kind: IssueKind,
+        /// The byte range of the offending mark within the text.
+        pub byte_range: Range<usize>,
+        /// A suggested fix, if any.
+        pub suggestion: Option<Suggestion>,
+    }
+
+    impl HebrewTextIssue {
+        /// Returns the severity of the issue.
+        pub fn severity(&self) -> Severity {
+            self.kind.severity()
+        }
+    }
+
+    impl fmt::Display for HebrewTextIssue {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let severity = match self.severity() {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            write!(
+                f,
+                "{} {} at {}..{}: {}",
+                self.kind.code(),
+                severity,
+                self.byte_range.start,
+                self.byte_range.end,
+                self.kind
+            )
+        }
+    }
+
+    /// An iterator over the issues of a text, see [`validate`].
+    #[derive(Debug, Clone)]
+    pub struct Issues<'a> {
+        clusters: Clusters<'a>,
+        current: Option<Cluster<'a>>,
+        // the last letter, the base of the marks after a combining grapheme joiner
+        letter: Option<Cluster<'a>>,
+        // the byte offset (within the text) of the next mark of the current cluster
+        position: usize,
+    }
+
+    /// Returns an iterator over the malformed sequences of a pointed text.
+    ///
+    /// The marks of every letter are checked in order; a mark is reported at most once.
+    /// Reserved code points of the Hebrew blocks are reported as well.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{validate, IssueKind, Severity, Suggestion};
+    ///
+    /// // a shin dot on a samekh
+    /// let mut issues = validate("ס\u{05C1}ֵפֶר");
+    /// let issue = issues.next().unwrap();
+    /// assert_eq!(issue.kind, IssueKind::ShinDotOnNonShin);
+    /// assert_eq!(issue.byte_range, 2..4);
+    /// assert_eq!(issue.suggestion, Some(Suggestion::Remove));
+    /// assert_eq!(issue.severity(), Severity::Error);
+    /// assert_eq!(issue.to_string(), "HEB003 error at 2..4: shin or sin dot on a letter other than shin");
+    /// assert!(issues.next().is_none());
+    ///
+    /// assert_eq!(validate("שָׁלוֹם").count(), 0);
+    /// ```
+    pub fn validate(s: &str) -> Issues<'_> {
+        Issues {
+            clusters: clusters(s),
+            current: None,
+            letter: None,
+            position: 0,
+        }
+    }
+
+    impl<'a> Issues<'a> {
+        // checks the mark `c` of a cluster, `rest` is the text of the cluster starting at `c`
+        fn check(
+            &self,
+            cluster: &Cluster<'a>,
+            c: char,
+            rest: &str,
+        ) -> Option<(IssueKind, Option<Suggestion>)> {
+            let remove = Some(Suggestion::Remove);
+            let letter = if is_letter(cluster) {
+                cluster
+            } else if cluster.base.map_or(false, is_adj_combining_grapheme_joiner) {
+                // the marks after a combining grapheme joiner belong to the preceding letter
+                match &self.letter {
+                    Some(letter) => letter,
+                    None => return Some((IssueKind::OrphanMark, remove)),
+                }
+            } else {
+                return Some((IssueKind::OrphanMark, remove));
+            };
+            let base = letter.base.unwrap_or_default();
+            // the marks before `c`, including the points implied by an APF letter
+            let earlier = cluster.marks().count() - rest.chars().count();
+            if cluster.marks().take(earlier).any(|m| m == c) {
+                return Some((IssueKind::DuplicateMark, remove));
+            }
+            let is_vowel = |m: char| is_hbr_point_vowel(m) || is_hbr_point_semi_vowel(m);
+            if is_vowel(c) && cluster.marks().take(earlier).any(is_vowel) {
+                return Some((IssueKind::MultipleVowels, remove));
+            }
+            if (is_hbr_point_shin_dot(c) || is_hbr_point_sin_dot(c)) && !is_hbr_consonant_shin(base)
+            {
+                return Some((IssueKind::ShinDotOnNonShin, remove));
+            }
+            if is_hbr_point_holam_haser_for_vav(c) && !is_hbr_consonant_vav(base) {
+                return Some((
+                    IssueKind::HolamHaserOnNonVav,
+                    Some(Suggestion::Replace('\u{05B9}')),
+                ));
+            }
+            if is_hbr_point_dagesh_or_mapiq(c) {
+                let word_final = !self
+                    .clusters
+                    .clone()
+                    .next()
+                    .map_or(false, |next| is_letter(&next));
+                let guttural = is_hbr_consonant_alef(base)
+                    || is_hbr_consonant_het(base)
+                    || is_hbr_consonant_ayin(base)
+                    || is_hbr_consonant_resh(base)
+                    || (is_hbr_consonant_he(base) && !word_final);
+                if guttural {
+                    return Some((IssueKind::DageshOnGuttural, remove));
+                }
+            }
+            None
+        }
+    }
+
+    impl<'a> Iterator for Issues<'a> {
+        type Item = HebrewTextIssue;
+
+        fn next(&mut self) -> Option<HebrewTextIssue> {
+            loop {
+                let cluster = match self.current.take() {
+                    Some(cluster) => cluster,
+                    None => {
+                        let cluster = self.clusters.next()?;
+                        // skip the (possibly precomposed) base character
+                        let base = match cluster.base {
+                            Some(_) => cluster.text.chars().next().map_or(0, char::len_utf8),
+                            None => 0,
+                        };
+                        self.position = cluster.range.start + base;
+                        if is_letter(&cluster) {
+                            self.letter = Some(cluster.clone());
+                        } else if !cluster.base.map_or(false, is_adj_combining_grapheme_joiner) {
+                            self.letter = None;
+                        }
+                        let reserved = cluster.text.chars().next().map_or(false, |c| {
+                            script_hbr_membership(c) == BlockMembership::Reserved
+                        });
+                        if base > 0 && reserved {
+                            let byte_range = cluster.range.start..self.position;
+                            self.current = Some(cluster);
+                            return Some(HebrewTextIssue {
+                                kind: IssueKind::ReservedCodePoint,
+                                byte_range,
+                                suggestion: Some(Suggestion::Remove),
+                            });
+                        }
+                        cluster
+                    }
+                };
+                let rest = &cluster.text[self.position - cluster.range.start..];
+                let c = match rest.chars().next() {
+                    Some(c) => c,
+                    None => continue,
+                };
+                let start = self.position;
+                self.position += c.len_utf8();
+                let issue = self.check(&cluster, c, rest);
+                self.current = Some(cluster);
+                if let Some((kind, suggestion)) = issue {
+                    return Some(HebrewTextIssue {
+                        kind,
+                        byte_range: start..self.position,
+                        suggestion,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub mod text_repair {
+    use crate::phonology::letters::is_letter;
+    use crate::phonology::vav::consonant_holams;
+    use crate::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+
+    /// The fixes applied by [`repair`].
+    ///
+    /// All fixes are enabled by default.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RepairOptions {
+        /// Remove a mark that occurs more than once on a letter.
+        pub dedupe_marks: bool,
+        /// Put the marks of a letter in canonical order (the order of normalized text): vowel,
+        /// dagesh, meteg, rafe, shin or sin dot, accents.
+        pub reorder_marks: bool,
+        /// Move a shin or sin dot from the letter after an undotted shin to that shin and
+        /// remove it from other letters.
+        pub fix_shin_dots: bool,
+        /// Change a holam (U+05B9) on a consonantal vav (see
+        /// [`VavKind::ConsonantHolam`](crate::VavKind::ConsonantHolam)) into a holam haser for
+        /// vav (U+05BA), like [`normalize_vav`](crate::normalize_vav); a precomposed U+FB4B is
+        /// decomposed.
+        pub holam_haser: bool,
+        /// Replace an ASCII apostrophe after a letter by a geresh (U+05F3).
+        pub geresh: bool,
+    }
+
+    impl Default for RepairOptions {
+        fn default() -> Self {
+            RepairOptions {
+                dedupe_marks: true,
+                reorder_marks: true,
+                fix_shin_dots: true,
+                holam_haser: true,
+                geresh: true,
+            }
+        }
+    }
+
+    /// The kind of an edit made by [`repair`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum RepairKind {
+        /// A repeated mark was removed.
+        DuplicateMark,
+        /// The marks of a letter were reordered.
+        MarkOrder,
+        /// A shin or sin dot was moved or removed.
+        ShinDot,
+        /// A holam was changed into a holam haser for vav.
+        HolamHaser,
+        /// An apostrophe was replaced by a geresh.
+        Geresh,
+    }
+
+    /// An edit made by [`repair`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct RepairChange {
+        /// The kind of the edit.
+        pub kind: RepairKind,
+        /// The byte range of the edited letters within the original text.
+        pub byte_range: Range<usize>,
+    }
+
+    /// The result of [`repair`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Repaired {
+        /// The repaired text.
+        pub text: String,
+        /// The edits, ordered by their position in the original text.
+        pub changes: Vec<RepairChange>,
+    }
+
+    // a cluster that can be edited
+    struct Unit<'a> {
+        range: Range<usize>,
+        base: &'a str,
+        // the decomposed base, if it is a letter
+        letter: Option<char>,
+        // the points implied by a precomposed APF letter
+        implied: Vec<char>,
+        marks: Vec<char>,
+    }
+
+    impl<'a> Unit<'a> {
+        fn has_mark(&self, predicate: fn(char) -> bool) -> bool {
+            self.implied
+                .iter()
+                .chain(&self.marks)
+                .any(|c| predicate(*c))
+        }
+    }
+
+    /// Applies safe fixes for common errors in pointed text and returns the repaired text
+    /// together with a log of the edits.
+    ///
+    /// See [`validate`] for reporting the issues without changing the text.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{repair, RepairKind, RepairOptions};
+    ///
+    /// // the shin dot was typed on the letter after the shin, the dagesh twice
+    /// let repaired = repair("של\u{05C1}\u{05B8}ום בּּ", RepairOptions::default());
+    /// assert_eq!(repaired.text, "ש\u{05C1}ל\u{05B8}ום בּ");
+    /// let kinds: Vec<RepairKind> = repaired.changes.iter().map(|change| change.kind).collect();
+    /// assert_eq!(kinds, [RepairKind::ShinDot, RepairKind::DuplicateMark]);
+    ///
+    /// let repaired = repair("ג' ב\u{05B8}\u{05BC}\u{05BC}", RepairOptions { geresh: false, ..RepairOptions::default() });
+    /// assert_eq!(repaired.text, "ג' ב\u{05B8}\u{05BC}");
+    /// ```
+    pub fn repair(s: &str, options: RepairOptions) -> Repaired {
+        let mut units: Vec<Unit<'_>> = clusters(s)
+            .map(|cluster| {
+                let base_len = match cluster.base {
+                    Some(_) => cluster.text.chars().next().map_or(0, char::len_utf8),
+                    None => 0,
+                };
+                let marks: Vec<char> = cluster.text[base_len..].chars().collect();
+                let implied = cluster
+                    .marks()
+                    .take(cluster.marks().count() - marks.len())
+                    .collect();
+                Unit {
+                    range: cluster.range.clone(),
+                    base: &cluster.text[..base_len],
+                    letter: cluster.base.filter(|_| is_letter(&cluster)),
+                    implied,
+                    marks,
+                }
+            })
+            .collect();
+        let consonant_holams: Vec<usize> = if options.holam_haser {
+            consonant_holams(s).map(|range| range.start).collect()
+        } else {
+            Vec::new()
+        };
+        let mut changes = Vec::new();
+        for i in 0..units.len() {
+            let (before, after) = units.split_at_mut(i);
+            let unit = &mut after[0];
+            let previous = before.last_mut();
+            let letter = match unit.letter {
+                Some(letter) => letter,
+                None => {
+                    let after_letter = previous.map_or(false, |p| p.letter.is_some());
+                    if options.geresh && unit.base == "'" && after_letter {
+                        unit.base = "\u{05F3}";
+                        changes.push(RepairChange {
+                            kind: RepairKind::Geresh,
+                            byte_range: unit.range.clone(),
+                        });
+                    }
+                    continue;
+                }
+            };
+            if options.dedupe_marks {
+                let mut seen = unit.implied.clone();
+                let count = unit.marks.len();
+                unit.marks.retain(|c| {
+                    let duplicate = seen.contains(c);
+                    seen.push(*c);
+                    !duplicate
+                });
+                if unit.marks.len() != count {
+                    changes.push(RepairChange {
+                        kind: RepairKind::DuplicateMark,
+                        byte_range: unit.range.clone(),
+                    });
+                }
+            }
+            if options.fix_shin_dots && unit.marks.iter().any(|c| is_dot(*c)) {
+                let mut byte_range = unit.range.clone();
+                let dot = unit.marks.iter().copied().find(|c| is_dot(*c));
+                if is_hbr_consonant_shin(letter) {
+                    // keep the first dot of a shin
+                    let mut dotted = unit.implied.iter().any(|c| is_dot(*c));
+                    let count = unit.marks.len();
+                    unit.marks.retain(|c| {
+                        let keep = !is_dot(*c) || !dotted;
+                        dotted |= is_dot(*c);
+                        keep
+                    });
+                    if unit.marks.len() != count {
+                        changes.push(RepairChange {
+                            kind: RepairKind::ShinDot,
+                            byte_range,
+                        });
+                    }
+                } else {
+                    unit.marks.retain(|c| !is_dot(*c));
+                    if let Some(previous) = previous {
+                        let undotted_shin = previous.letter.map_or(false, is_hbr_consonant_shin)
+                            && !previous.has_mark(is_dot);
+                        if let (true, Some(dot)) = (undotted_shin, dot) {
+                            // the dot goes before the accents
+                            let position = previous
+                                .marks
+                                .iter()
+                                .take_while(|c| combining_class(**c) <= combining_class(dot))
+                                .count();
+                            previous.marks.insert(position, dot);
+                            byte_range.start = previous.range.start;
+                        }
+                    }
+                    changes.push(RepairChange {
+                        kind: RepairKind::ShinDot,
+                        byte_range,
+                    });
+                }
+            }
+            if options.holam_haser && consonant_holams.binary_search(&unit.range.start).is_ok() {
+                let mut changed = false;
+                if unit.implied.iter().any(|c| is_hbr_point_holam(*c)) {
+                    // U+FB4B
+                    unit.base = "\u{05D5}";
+                    unit.implied.clear();
+                    let position = unit
+                        .marks
+                        .iter()
+                        .take_while(|c| combining_class(**c) <= combining_class('\u{05BA}'))
+                        .count();
+                    unit.marks.insert(position, '\u{05BA}');
+                    changed = true;
+                }
+                for holam in unit.marks.iter_mut().filter(|c| is_hbr_point_holam(**c)) {
+                    *holam = '\u{05BA}';
+                    changed = true;
+                }
+                if changed {
+                    changes.push(RepairChange {
+                        kind: RepairKind::HolamHaser,
+                        byte_range: unit.range.clone(),
+                    });
+                }
+            }
+        }
+        if options.reorder_marks {
+            for unit in units.iter_mut().filter(|unit| unit.letter.is_some()) {
+                if unit
+                    .marks
+                    .windows(2)
+                    .any(|pair| combining_class(pair[0]) > combining_class(pair[1]))
+                {
+                    unit.marks.sort_by_key(|c| combining_class(*c));
+                    changes.push(RepairChange {
+                        kind: RepairKind::MarkOrder,
+                        byte_range: unit.range.clone(),
+                    });
+                }
+            }
+        }
+        changes.sort_by_key(|change| change.byte_range.start);
+
+        let mut text = String::with_capacity(s.len());
+        for unit in &units {
+            text.push_str(unit.base);
+            text.extend(&unit.marks);
+        }
+        Repaired { text, changes }
+    }
+
+    // a shin dot or a sin dot
+    fn is_dot(c: char) -> bool {
+        is_hbr_point_shin_dot(c) || is_hbr_point_sin_dot(c)
+    }
+
+    // the canonical combining class of a mark (UnicodeData.txt), which defines the order of
+    // the marks in normalized text
+    fn combining_class(c: char) -> u8 {
+        match c {
+            '\u{05B0}' => 10,
+            '\u{05B1}' => 11,
+            '\u{05B2}' => 12,
+            '\u{05B3}' => 13,
+            '\u{05B4}' => 14,
+            '\u{05B5}' => 15,
+            '\u{05B6}' => 16,
+            '\u{05B7}' => 17,
+            '\u{05B8}' | '\u{05C7}' => 18,
+            '\u{05B9}' | '\u{05BA}' => 19,
+            '\u{05BB}' => 20,
+            '\u{05BC}' => 21,
+            '\u{05BD}' => 22,
+            '\u{05BF}' => 23,
+            '\u{05C1}' => 24,
+            '\u{05C2}' => 25,
+            '\u{FB1E}' => 26,
+            '\u{059A}' | '\u{05AD}' => 222,
+            '\u{05AE}' => 228,
+            '\u{0591}'
+            | '\u{0596}'
+            | '\u{059B}'
+            | '\u{05A2}'..='\u{05A7}'
+            | '\u{05AA}'
+            | '\u{05C5}' => 220,
+            _ if is_hbr_accent(c) || is_hbr_mark(c) => 230,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn kinds(text: &str) -> impl Iterator<Item = IssueKind> + '_ {
+        validate(text).map(|issue| issue.kind)
+    }
+
+    #[test]
+    fn test_validate() {
+        use IssueKind::*;
+        assert!(kinds("בְּרֵאשִׁית בָּרָא").eq([IssueKind::OrphanMark; 0]));
+        assert!(kinds("\u{05B8}ב").eq([OrphanMark]));
+        assert!(kinds("ב \u{05B8}").eq([OrphanMark]));
+        assert!(kinds("בָּ\u{05B7}").eq([MultipleVowels]));
+        assert!(kinds("\u{FB2E}\u{05B8}").eq([MultipleVowels]));
+        // the Masoretic ירושלם, with and without combining grapheme joiner
+        assert!(kinds("יְרוּשָׁלַ\u{05B4}ם").eq([MultipleVowels]));
+        assert!(validate("יְרוּשָׁלַ\u{05B4}ם").all(|issue| issue.severity() == Severity::Warning));
+        assert!(kinds("יְרוּשָׁלַ\u{034F}\u{05B4}ם").eq([IssueKind::OrphanMark; 0]));
+        assert!(kinds("\u{034F}\u{05B4}").eq([OrphanMark]));
+        assert!(kinds("ס\u{034F}\u{05C1}").eq([ShinDotOnNonShin]));
+        assert!(kinds("ס\u{05C2}").eq([ShinDotOnNonShin]));
+        assert!(kinds("\u{FB2A}").eq([IssueKind::OrphanMark; 0]));
+        assert!(kinds("חּ").eq([DageshOnGuttural]));
+        assert!(kinds("אַרְצָהּ").eq([IssueKind::OrphanMark; 0]));
+        assert!(kinds("הּוּא").eq([DageshOnGuttural]));
+        assert!(kinds("ל\u{05BA}").eq([HolamHaserOnNonVav]));
+        assert!(kinds("ו\u{05BA}").eq([IssueKind::OrphanMark; 0]));
+        assert!(kinds("בּּ").eq([DuplicateMark]));
+        assert!(kinds("א\u{05EB}\u{05B8}").eq([ReservedCodePoint, OrphanMark]));
+        assert!(kinds("\u{FB37}").eq([ReservedCodePoint]));
+
+        let issue = validate("ל\u{05BA}").next().unwrap();
+        assert_eq!(issue.suggestion, Some(Suggestion::Replace('\u{05B9}')));
+        assert_eq!(issue.byte_range, 2..4);
+        assert_eq!(issue.severity(), Severity::Error);
+        assert!(Severity::Warning < Severity::Error);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_repair() {
+        let fix = |text| repair(text, RepairOptions::default());
+        // duplicates, also against the points of a precomposed letter
+        assert_eq!(fix("בּּ").text, "בּ");
+        assert_eq!(fix("\u{FB35}\u{05BC}").text, "\u{FB35}");
+        // canonical order
+        let repaired = fix("ב\u{05BC}\u{05B8}\u{0591}\u{05BD}");
+        assert_eq!(repaired.text, "ב\u{05B8}\u{05BC}\u{05BD}\u{0591}");
+        assert_eq!(repaired.changes[0].kind, RepairKind::MarkOrder);
+        // shin and sin dots
+        assert_eq!(fix("ש\u{05C1}\u{05C2}").text, "ש\u{05C1}");
+        assert_eq!(fix("ס\u{05C2}\u{05B5}").text, "ס\u{05B5}");
+        let repaired = fix("ש\u{05BC}\u{05B8}ל\u{05C1}");
+        assert_eq!(repaired.text, "ש\u{05B8}\u{05BC}\u{05C1}ל");
+        assert_eq!(repaired.changes[0].byte_range, 0..10);
+        // holam haser for vav
+        assert_eq!(fix("עָוֹן").text, "עָו\u{05BA}ן");
+        assert_eq!(fix("שָׁלוֹם").text, "שָׁלוֹם");
+        assert_eq!(fix("חַוֹּת").text, "חַו\u{05BA}\u{05BC}ת");
+        assert_eq!(fix("וֹ").text, "ו\u{05BA}");
+        assert_eq!(fix("עָ\u{FB4B}ן").text, "עָו\u{05BA}ן");
+        assert_eq!(fix("ק\u{FB4B}ל").text, "ק\u{FB4B}ל");
+        // repair and normalize_vav agree
+        for text in ["עָוֹן", "חַוֹּת", "וֹ", "עָ\u{FB4B}ן", "שָׁלוֹם", "מִצְוֹת"]
+        {
+            assert_eq!(fix(text).text, normalize_vav(text));
+        }
+        // geresh
+        assert_eq!(fix("ג'").text, "ג\u{05F3}");
+        assert_eq!(fix("'a").text, "'a");
+
+        let text = "בְּרֵאשִׁית בָּרָא";
+        let repaired = fix(text);
+        assert_eq!(repaired.text, text);
+        assert!(repaired.changes.is_empty());
+    }
+}
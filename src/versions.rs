@@ -0,0 +1,201 @@
+pub mod unicode_versions {
+    use crate::*;
+
+    /// A version of the Unicode standard.
+    ///
+    /// Versions are ordered, e.g. `UnicodeVersion::V4_1 < UnicodeVersion::V11_0`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct UnicodeVersion {
+        /// The major version.
+        pub major: u8,
+        /// The minor version.
+        pub minor: u8,
+    }
+
+    impl UnicodeVersion {
+        /// Unicode 1.1, the Hebrew consonants, points and punctuation.
+        pub const V1_1: UnicodeVersion = UnicodeVersion::new(1, 1);
+        /// Unicode 2.0, the accents (cantillation marks).
+        pub const V2_0: UnicodeVersion = UnicodeVersion::new(2, 0);
+        /// Unicode 3.0, yod with hiriq (U+FB1D).
+        pub const V3_0: UnicodeVersion = UnicodeVersion::new(3, 0);
+        /// Unicode 4.1, atnah hafukh, lower dot, nun hafukha and qamats qatan.
+        pub const V4_1: UnicodeVersion = UnicodeVersion::new(4, 1);
+        /// Unicode 5.0, holam haser for vav.
+        pub const V5_0: UnicodeVersion = UnicodeVersion::new(5, 0);
+        /// Unicode 11.0, yod triangle.
+        pub const V11_0: UnicodeVersion = UnicodeVersion::new(11, 0);
+
+        /// Returns the version `major.minor`.
+        pub const fn new(major: u8, minor: u8) -> Self {
+            UnicodeVersion { major, minor }
+        }
+    }
+
+    /// Returns the Unicode version in which a character of the Hebrew script was introduced
+    /// (see DerivedAge.txt), `None` if the character is not part of the script.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{unicode_version, UnicodeVersion};
+    ///
+    /// assert_eq!(unicode_version('א'), Some(UnicodeVersion::V1_1));
+    /// assert_eq!(unicode_version('\u{05C7}'), Some(UnicodeVersion::V4_1));
+    /// assert_eq!(unicode_version('\u{05EF}'), Some(UnicodeVersion::V11_0));
+    /// assert_eq!(unicode_version('a'), None);
+    /// ```
+    pub const fn unicode_version(c: char) -> Option<UnicodeVersion> {
+        if !is_script_hbr(c) {
+            return None;
+        }
+        let version = match c {
+            '\u{05A2}' | '\u{05C5}'..='\u{05C7}' => UnicodeVersion::V4_1,
+            '\u{0591}'..='\u{05AF}' | '\u{05C4}' => UnicodeVersion::V2_0,
+            '\u{05BA}' => UnicodeVersion::V5_0,
+            '\u{05EF}' => UnicodeVersion::V11_0,
+            '\u{FB1D}' => UnicodeVersion::V3_0,
+            _ => UnicodeVersion::V1_1,
+        };
+        Some(version)
+    }
+
+    /// Checks if the given character of the Hebrew script is available in `version`.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{is_available_in, UnicodeVersion};
+    ///
+    /// assert!(is_available_in('\u{05B8}', UnicodeVersion::V1_1));
+    /// assert!(!is_available_in('\u{05C7}', UnicodeVersion::V2_0));
+    /// ```
+    pub const fn is_available_in(c: char, version: UnicodeVersion) -> bool {
+        match unicode_version(c) {
+            Some(introduced) => {
+                introduced.major < version.major
+                    || (introduced.major == version.major && introduced.minor <= version.minor)
+            }
+            None => false,
+        }
+    }
+
+    impl HebrewCollection {
+        /// Returns the members of the collection that are available on a system that
+        /// supports at least `version`.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{HebrewCollection, UnicodeVersion};
+        ///
+        /// let vowels = HebrewCollection::HbrPointVowel.min_version(UnicodeVersion::V2_0);
+        /// assert!(vowels.contains('\u{05B8}'));
+        /// assert!(!vowels.contains('\u{05C7}'));
+        /// assert!(!vowels.contains('\u{05BA}'));
+        /// ```
+        pub const fn min_version(self, version: UnicodeVersion) -> HebrewCharSet {
+            let chars = self.chars();
+            let mut set = HebrewCharSet::new();
+            let mut i = 0;
+            while i < chars.len() {
+                if is_available_in(chars[i], version) {
+                    set = set.with(chars[i]);
+                }
+                i += 1;
+            }
+            set
+        }
+    }
+
+    // the replacement of a character that is not available in an older version
+    #[cfg(feature = "alloc")]
+    fn fallback(c: char) -> &'static [char] {
+        match c {
+            // qamats qatan -> qamats
+            '\u{05C7}' => &['\u{05B8}'],
+            // holam haser for vav -> holam
+            '\u{05BA}' => &['\u{05B9}'],
+            // yod triangle -> yod
+            '\u{05EF}' => &['\u{05D9}'],
+            // yod with hiriq -> yod + hiriq
+            '\u{FB1D}' => &['\u{05D9}', '\u{05B4}'],
+            // accents, marks and nun hafukha are dropped
+            _ => &[],
+        }
+    }
+
+    /// Replaces the characters of the Hebrew script that are not available in `version` by
+    /// older equivalents, for devices with old fonts.
+    ///
+    /// - qamats qatan (U+05C7) becomes qamats (U+05B8),
+    /// - holam haser for vav (U+05BA) becomes holam (U+05B9),
+    /// - yod triangle (U+05EF) becomes yod (U+05D9),
+    /// - yod with hiriq (U+FB1D) becomes yod (U+05D9) with hiriq (U+05B4),
+    /// - other characters (accents, marks, nun hafukha) are removed.
+    ///
+    /// Characters outside the Hebrew script are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{downgrade, UnicodeVersion};
+    ///
+    /// assert_eq!(downgrade("כׇּל", UnicodeVersion::V2_0), "כָּל");
+    /// assert_eq!(downgrade("בְּרֵאשִׁ֖ית", UnicodeVersion::V1_1), "בְּרֵאשִׁית");
+    /// assert_eq!(downgrade("כׇּל", UnicodeVersion::V4_1), "כׇּל");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn downgrade(s: &str, version: UnicodeVersion) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(s.len());
+        for c in s.chars() {
+            if is_available_in(c, version) || !is_script_hbr(c) {
+                result.push(c);
+            } else {
+                result.extend(fallback(c).iter().filter(|f| is_available_in(**f, version)));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_unicode_version() {
+        for collection in HebrewCollection::ALL.iter().copied() {
+            for c in collection.chars().iter().copied() {
+                let version = unicode_version(c).unwrap();
+                assert!(version >= UnicodeVersion::V1_1 && version <= UnicodeVersion::V11_0);
+                assert!(is_available_in(c, UnicodeVersion::V11_0));
+                assert!(is_available_in(c, UnicodeVersion::new(15, 1)));
+            }
+            let all = collection.min_version(UnicodeVersion::V11_0);
+            assert_eq!(all, HebrewCharSet::from_collection(collection));
+        }
+        assert_eq!(unicode_version('\u{05A2}'), Some(UnicodeVersion::V4_1));
+        assert_eq!(unicode_version('\u{05A1}'), Some(UnicodeVersion::V2_0));
+        assert_eq!(unicode_version('\u{05BA}'), Some(UnicodeVersion::V5_0));
+        assert_eq!(unicode_version('\u{FB1D}'), Some(UnicodeVersion::V3_0));
+        assert_eq!(unicode_version('\u{FB1E}'), Some(UnicodeVersion::V1_1));
+        assert_eq!(unicode_version('\u{05C8}'), None);
+        assert!(!is_available_in('\u{05EF}', UnicodeVersion::new(10, 0)));
+        assert!(UnicodeVersion::new(4, 10) > UnicodeVersion::V4_1);
+
+        let accents = HebrewCollection::HbrAccent.min_version(UnicodeVersion::V2_0);
+        assert_eq!(accents.len(), 29);
+        assert!(HebrewCollection::HbrAccent
+            .min_version(UnicodeVersion::V1_1)
+            .is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_downgrade() {
+        let old = UnicodeVersion::V1_1;
+        assert_eq!(downgrade("\u{05EF}", old), "\u{05D9}");
+        assert_eq!(downgrade("\u{FB1D}", old), "\u{05D9}\u{05B4}");
+        assert_eq!(downgrade("\u{FB1D}", UnicodeVersion::V3_0), "\u{FB1D}");
+        assert_eq!(downgrade("עָו\u{05BA}ן", old), "עָו\u{05B9}ן");
+        assert_eq!(downgrade("\u{05C6}a", old), "a");
+        assert_eq!(downgrade("ב\u{05A2}", UnicodeVersion::V2_0), "ב");
+    }
+}
@@ -0,0 +1,155 @@
+pub mod hebrew_vowels {
+    /// The quality (sound) of a vowel.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VowelQuality {
+        /// a (patah, qamats, hataf patah).
+        A,
+        /// e (segol, tsere, hataf segol, sheva).
+        E,
+        /// i (hiriq).
+        I,
+        /// o (holam, qamats qatan, hataf qamats).
+        O,
+        /// u (qubuts).
+        U,
+    }
+
+    /// The length class of a vowel in the traditional grammar.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VowelClass {
+        /// qamats, tsere, holam (and holam haser for vav).
+        Long,
+        /// patah, segol, hiriq, qubuts, qamats qatan.
+        Short,
+        /// sheva and the hataf vowels.
+        Reduced,
+    }
+
+    /// The attributes of a vowel point, see [`vowel_info`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct VowelInfo {
+        /// The sound of the vowel; the vocal sheva is pronounced as e.
+        pub quality: VowelQuality,
+        /// The length class of the vowel.
+        pub class: VowelClass,
+        /// The name of the vowel, e.g. "hataf patah".
+        pub name: &'static str,
+        /// For a hataf vowel the full vowel it is composed of, for patah, segol and qamats
+        /// qatan the hataf vowel composed of it (e.g. patah and hataf patah). The pairing is
+        /// by sound: the hataf qamats is an o, so its counterpart is the qamats qatan and the
+        /// qamats (gadol) has none.
+        pub hataf_counterpart: Option<char>,
+    }
+
+    impl VowelInfo {
+        /// Checks if the vowel counts as a long vowel for the rules of the sheva and the
+        /// dagesh: a sheva after a long vowel is vocal (e.g. שָֽׁמְרוּ).
+        ///
+        /// Only the point is looked at: U+05B8 is always long, although it is read as a short
+        /// qamats qatan in a closed unstressed syllable. Callers that need to tell the two apart
+        /// run [`qamats_kind`](crate::qamats_kind) on the text and use the info of U+05C7 for a
+        /// qamats qatan.
+        ///
+        /// # Example
+        /// ```
+        /// use hebrew_unicode_script::{qamats_kind, vowel_info, QamatsKind};
+        ///
+        /// assert!(vowel_info('\u{05B5}').unwrap().is_long()); // tsere
+        /// assert!(!vowel_info('\u{05B6}').unwrap().is_long()); // segol
+        ///
+        /// // the first qamats of חָכְמָה is a qamats qatan
+        /// let text = "חָכְמָה";
+        /// let index = text.find('\u{05B8}').unwrap();
+        /// let qamats = match qamats_kind(text, index) {
+        ///     Some(QamatsKind::Qatan) => '\u{05C7}',
+        ///     _ => '\u{05B8}',
+        /// };
+        /// assert!(!vowel_info(qamats).unwrap().is_long());
+        /// ```
+        pub const fn is_long(&self) -> bool {
+            matches!(self.class, VowelClass::Long)
+        }
+    }
+
+    /// Returns the attributes of a vowel (see `is_hbr_point_vowel`) or semi-vowel (see
+    /// `is_hbr_point_semi_vowel`), `None` for other characters.
+    ///
+    /// The shuruk is written as a vav with dagesh and has no vowel point of its own.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::{vowel_info, VowelClass, VowelQuality};
+    ///
+    /// let hataf_patah = vowel_info('\u{05B2}').unwrap();
+    /// assert_eq!(hataf_patah.name, "hataf patah");
+    /// assert_eq!(hataf_patah.quality, VowelQuality::A);
+    /// assert_eq!(hataf_patah.class, VowelClass::Reduced);
+    /// assert_eq!(hataf_patah.hataf_counterpart, Some('\u{05B7}'));
+    ///
+    /// assert_eq!(vowel_info('\u{05B7}').unwrap().hataf_counterpart, Some('\u{05B2}'));
+    /// assert_eq!(vowel_info('\u{05BC}'), None); // dagesh
+    /// ```
+    pub const fn vowel_info(c: char) -> Option<VowelInfo> {
+        use VowelClass::*;
+        use VowelQuality::*;
+        let (quality, class, name, hataf_counterpart) = match c {
+            '\u{05B0}' => (E, Reduced, "sheva", None),
+            '\u{05B1}' => (E, Reduced, "hataf segol", Some('\u{05B6}')),
+            '\u{05B2}' => (A, Reduced, "hataf patah", Some('\u{05B7}')),
+            '\u{05B3}' => (O, Reduced, "hataf qamats", Some('\u{05C7}')),
+            '\u{05B4}' => (I, Short, "hiriq", None),
+            '\u{05B5}' => (E, Long, "tsere", None),
+            '\u{05B6}' => (E, Short, "segol", Some('\u{05B1}')),
+            '\u{05B7}' => (A, Short, "patah", Some('\u{05B2}')),
+            '\u{05B8}' => (A, Long, "qamats", None),
+            '\u{05B9}' => (O, Long, "holam", None),
+            '\u{05BA}' => (O, Long, "holam haser for vav", None),
+            '\u{05BB}' => (U, Short, "qubuts", None),
+            '\u{05C7}' => (O, Short, "qamats qatan", Some('\u{05B3}')),
+            _ => return None,
+        };
+        Some(VowelInfo {
+            quality,
+            class,
+            name,
+            hataf_counterpart,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_vowel_info() {
+        for code in 0x0590..=0x05FF {
+            let c = char::from_u32(code).unwrap();
+            let info = vowel_info(c);
+            assert_eq!(
+                info.is_some(),
+                is_hbr_point_vowel(c) || is_hbr_point_semi_vowel(c),
+                "{:04X}",
+                code
+            );
+            // a hataf vowel and its full vowel are paired both ways and have the same quality
+            if let Some(info) = info {
+                if let Some(counterpart) = info.hataf_counterpart {
+                    let other = vowel_info(counterpart).unwrap();
+                    assert_eq!(other.hataf_counterpart, Some(c), "{:04X}", code);
+                    assert_eq!(other.quality, info.quality, "{:04X}", code);
+                    assert_ne!(
+                        other.class == VowelClass::Reduced,
+                        info.class == VowelClass::Reduced
+                    );
+                }
+            }
+        }
+        assert_eq!(vowel_info('\u{05B0}').unwrap().hataf_counterpart, None);
+        assert_eq!(vowel_info('\u{05B8}').unwrap().hataf_counterpart, None);
+        assert!(vowel_info('\u{05BA}').unwrap().is_long());
+        assert!(vowel_info('\u{05B8}').unwrap().is_long());
+        assert!(!vowel_info('\u{05B4}').unwrap().is_long());
+        assert!(!vowel_info('\u{05B3}').unwrap().is_long());
+    }
+}
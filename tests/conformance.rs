@@ -0,0 +1,335 @@
+//! Checks the predicates of the crate against the Unicode Character Database (see the
+//! `ucd` directory and `cargo xtask ucd`).
+//!
+//! After upgrading the vendored files to a new Unicode version, these tests show which
+//! predicates have to follow.
+
+use hebrew_unicode_script::*;
+
+type Predicate = fn(char) -> bool;
+
+// every single character predicate with the Unicode name of its character
+const LEAVES: &[(Predicate, &str)] = &[
+    (
+        is_adj_combining_grapheme_joiner,
+        "COMBINING GRAPHEME JOINER",
+    ),
+    (is_hbr_accent_etnahta, "HEBREW ACCENT ETNAHTA"),
+    (is_hbr_accent_segol, "HEBREW ACCENT SEGOL"),
+    (is_hbr_accent_shalshelet, "HEBREW ACCENT SHALSHELET"),
+    (is_hbr_accent_zaqef_qatan, "HEBREW ACCENT ZAQEF QATAN"),
+    (is_hbr_accent_zaqef_gadol, "HEBREW ACCENT ZAQEF GADOL"),
+    (is_hbr_accent_tipeha, "HEBREW ACCENT TIPEHA"),
+    (is_hbr_accent_revia, "HEBREW ACCENT REVIA"),
+    (is_hbr_accent_zarqa, "HEBREW ACCENT ZARQA"),
+    (is_hbr_accent_pashta, "HEBREW ACCENT PASHTA"),
+    (is_hbr_accent_yetiv, "HEBREW ACCENT YETIV"),
+    (is_hbr_accent_tevir, "HEBREW ACCENT TEVIR"),
+    (is_hbr_accent_geresh, "HEBREW ACCENT GERESH"),
+    (is_hbr_accent_geresh_muqdam, "HEBREW ACCENT GERESH MUQDAM"),
+    (is_hbr_accent_gershayim, "HEBREW ACCENT GERSHAYIM"),
+    (is_hbr_accent_qarney_para, "HEBREW ACCENT QARNEY PARA"),
+    (is_hbr_accent_telisha_gedola, "HEBREW ACCENT TELISHA GEDOLA"),
+    (is_hbr_accent_pazer, "HEBREW ACCENT PAZER"),
+    (is_hbr_accent_atnah_hafukh, "HEBREW ACCENT ATNAH HAFUKH"),
+    (is_hbr_accent_munah, "HEBREW ACCENT MUNAH"),
+    (is_hbr_accent_mahapakh, "HEBREW ACCENT MAHAPAKH"),
+    (is_hbr_accent_merkha, "HEBREW ACCENT MERKHA"),
+    (is_hbr_accent_merkha_kefula, "HEBREW ACCENT MERKHA KEFULA"),
+    (is_hbr_accent_darga, "HEBREW ACCENT DARGA"),
+    (is_hbr_accent_qadma, "HEBREW ACCENT QADMA"),
+    (is_hbr_accent_telisha_qetana, "HEBREW ACCENT TELISHA QETANA"),
+    (is_hbr_accent_yerah_ben_yomo, "HEBREW ACCENT YERAH BEN YOMO"),
+    (is_hbr_accent_ole, "HEBREW ACCENT OLE"),
+    (is_hbr_accent_iluy, "HEBREW ACCENT ILUY"),
+    (is_hbr_accent_dehi, "HEBREW ACCENT DEHI"),
+    (is_hbr_accent_zinor, "HEBREW ACCENT ZINOR"),
+    (is_hbr_mark_masora_circle, "HEBREW MARK MASORA CIRCLE"),
+    (is_hbr_point_sheva, "HEBREW POINT SHEVA"),
+    (is_hbr_point_hataf_segol, "HEBREW POINT HATAF SEGOL"),
+    (is_hbr_point_hataf_patah, "HEBREW POINT HATAF PATAH"),
+    (is_hbr_point_hataf_qamats, "HEBREW POINT HATAF QAMATS"),
+    (is_hbr_point_hiriq, "HEBREW POINT HIRIQ"),
+    (is_hbr_point_tsere, "HEBREW POINT TSERE"),
+    (is_hbr_point_segol, "HEBREW POINT SEGOL"),
+    (is_hbr_point_patah, "HEBREW POINT PATAH"),
+    (is_hbr_point_qamats, "HEBREW POINT QAMATS"),
+    (is_hbr_point_holam, "HEBREW POINT HOLAM"),
+    (
+        is_hbr_point_holam_haser_for_vav,
+        "HEBREW POINT HOLAM HASER FOR VAV",
+    ),
+    (is_hbr_point_qubuts, "HEBREW POINT QUBUTS"),
+    (is_hbr_point_dagesh_or_mapiq, "HEBREW POINT DAGESH OR MAPIQ"),
+    (is_hbr_point_meteg, "HEBREW POINT METEG"),
+    (is_hbr_punctuation_maqaf, "HEBREW PUNCTUATION MAQAF"),
+    (is_hbr_point_rafe, "HEBREW POINT RAFE"),
+    (is_hbr_punctuation_paseq, "HEBREW PUNCTUATION PASEQ"),
+    (is_hbr_point_shin_dot, "HEBREW POINT SHIN DOT"),
+    (is_hbr_point_sin_dot, "HEBREW POINT SIN DOT"),
+    (is_hbr_punctuation_sof_pasuq, "HEBREW PUNCTUATION SOF PASUQ"),
+    (is_hbr_mark_upper_dot, "HEBREW MARK UPPER DOT"),
+    (is_hbr_mark_lower_dot, "HEBREW MARK LOWER DOT"),
+    (
+        is_hbr_punctuation_nun_hafukha,
+        "HEBREW PUNCTUATION NUN HAFUKHA",
+    ),
+    (is_hbr_point_qamats_qatan, "HEBREW POINT QAMATS QATAN"),
+    (is_hbr_consonant_alef, "HEBREW LETTER ALEF"),
+    (is_hbr_consonant_bet, "HEBREW LETTER BET"),
+    (is_hbr_consonant_gimel, "HEBREW LETTER GIMEL"),
+    (is_hbr_consonant_dalet, "HEBREW LETTER DALET"),
+    (is_hbr_consonant_he, "HEBREW LETTER HE"),
+    (is_hbr_consonant_vav, "HEBREW LETTER VAV"),
+    (is_hbr_consonant_zayin, "HEBREW LETTER ZAYIN"),
+    (is_hbr_consonant_het, "HEBREW LETTER HET"),
+    (is_hbr_consonant_tet, "HEBREW LETTER TET"),
+    (is_hbr_consonant_yod, "HEBREW LETTER YOD"),
+    (is_hbr_consonant_final_kaf, "HEBREW LETTER FINAL KAF"),
+    (is_hbr_consonant_kaf, "HEBREW LETTER KAF"),
+    (is_hbr_consonant_lamed, "HEBREW LETTER LAMED"),
+    (is_hbr_consonant_final_mem, "HEBREW LETTER FINAL MEM"),
+    (is_hbr_consonant_mem, "HEBREW LETTER MEM"),
+    (is_hbr_consonant_final_nun, "HEBREW LETTER FINAL NUN"),
+    (is_hbr_consonant_nun, "HEBREW LETTER NUN"),
+    (is_hbr_consonant_samekh, "HEBREW LETTER SAMEKH"),
+    (is_hbr_consonant_ayin, "HEBREW LETTER AYIN"),
+    (is_hbr_consonant_final_pe, "HEBREW LETTER FINAL PE"),
+    (is_hbr_consonant_pe, "HEBREW LETTER PE"),
+    (is_hbr_consonant_final_tsadi, "HEBREW LETTER FINAL TSADI"),
+    (is_hbr_consonant_tsadi, "HEBREW LETTER TSADI"),
+    (is_hbr_consonant_qof, "HEBREW LETTER QOF"),
+    (is_hbr_consonant_resh, "HEBREW LETTER RESH"),
+    (is_hbr_consonant_shin, "HEBREW LETTER SHIN"),
+    (is_hbr_consonant_tav, "HEBREW LETTER TAV"),
+    (is_hbr_yod_triangle, "HEBREW YOD TRIANGLE"),
+    (
+        is_hbr_ligature_yiddisch_double_vav,
+        "HEBREW LIGATURE YIDDISH DOUBLE VAV",
+    ),
+    (
+        is_hbr_ligature_yiddisch_vav_yod,
+        "HEBREW LIGATURE YIDDISH VAV YOD",
+    ),
+    (
+        is_hbr_ligature_yiddisch_double_yod,
+        "HEBREW LIGATURE YIDDISH DOUBLE YOD",
+    ),
+    (is_hbr_punctuation_geresh, "HEBREW PUNCTUATION GERESH"),
+    (is_hbr_punctuation_gershayim, "HEBREW PUNCTUATION GERSHAYIM"),
+    (is_adj_right_to_left_mark, "RIGHT-TO-LEFT MARK"),
+    (is_adj_new_sheqel_sign, "NEW SHEQEL SIGN"),
+    (is_adj_alef_symbol, "ALEF SYMBOL"),
+    (is_adj_bet_symbol, "BET SYMBOL"),
+    (is_adj_gimel_symbol, "GIMEL SYMBOL"),
+    (is_adj_dalet_symbol, "DALET SYMBOL"),
+    (is_adj_dotted_circle, "DOTTED CIRCLE"),
+    (
+        is_apf_consonant_vowel_yod_hiriq,
+        "HEBREW LETTER YOD WITH HIRIQ",
+    ),
+    (
+        is_apf_point_judeo_spanish_varika,
+        "HEBREW POINT JUDEO-SPANISH VARIKA",
+    ),
+    (
+        is_apf_ligature_yiddisch_yod_yod_patah,
+        "HEBREW LIGATURE YIDDISH YOD YOD PATAH",
+    ),
+    (
+        is_apf_consonant_alternative_ayin,
+        "HEBREW LETTER ALTERNATIVE AYIN",
+    ),
+    (is_apf_consonant_wide_alef, "HEBREW LETTER WIDE ALEF"),
+    (is_apf_consonant_wide_dalet, "HEBREW LETTER WIDE DALET"),
+    (is_apf_consonant_wide_he, "HEBREW LETTER WIDE HE"),
+    (is_apf_consonant_wide_kaf, "HEBREW LETTER WIDE KAF"),
+    (is_apf_consonant_wide_lamed, "HEBREW LETTER WIDE LAMED"),
+    (
+        is_apf_consonant_wide_final_mem,
+        "HEBREW LETTER WIDE FINAL MEM",
+    ),
+    (is_apf_consonant_wide_resh, "HEBREW LETTER WIDE RESH"),
+    (is_apf_consonant_wide_tav, "HEBREW LETTER WIDE TAV"),
+    (
+        is_apf_letter_alternative_plus_sign,
+        "HEBREW LETTER ALTERNATIVE PLUS SIGN",
+    ),
+    (
+        is_apf_consonant_vowel_shin_shindot,
+        "HEBREW LETTER SHIN WITH SHIN DOT",
+    ),
+    (
+        is_apf_consonant_vowel_shin_sindot,
+        "HEBREW LETTER SHIN WITH SIN DOT",
+    ),
+    (
+        is_apf_consonant_vowel_shin_dagesh_shindot,
+        "HEBREW LETTER SHIN WITH DAGESH AND SHIN DOT",
+    ),
+    (
+        is_apf_consonant_vowel_shin_dagesh_sindot,
+        "HEBREW LETTER SHIN WITH DAGESH AND SIN DOT",
+    ),
+    (
+        is_apf_consonant_vowel_alef_patah,
+        "HEBREW LETTER ALEF WITH PATAH",
+    ),
+    (
+        is_apf_consonant_vowel_alef_qamats,
+        "HEBREW LETTER ALEF WITH QAMATS",
+    ),
+    (
+        is_apf_consonant_vowel_alef_mapiq,
+        "HEBREW LETTER ALEF WITH MAPIQ",
+    ),
+    (
+        is_apf_consonant_vowel_bet_dagesh,
+        "HEBREW LETTER BET WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_gimmel_dagesh,
+        "HEBREW LETTER GIMEL WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_dalet_dagesh,
+        "HEBREW LETTER DALET WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_he_mapiq,
+        "HEBREW LETTER HE WITH MAPIQ",
+    ),
+    (
+        is_apf_consonant_vowel_vav_dagesh,
+        "HEBREW LETTER VAV WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_zayin_dagesh,
+        "HEBREW LETTER ZAYIN WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_tet_dagesh,
+        "HEBREW LETTER TET WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_yod_dagesh,
+        "HEBREW LETTER YOD WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_final_kaf_dagesh,
+        "HEBREW LETTER FINAL KAF WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_kaf_dagesh,
+        "HEBREW LETTER KAF WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_lamed_dagesh,
+        "HEBREW LETTER LAMED WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_mem_dagesh,
+        "HEBREW LETTER MEM WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_nun_dagesh,
+        "HEBREW LETTER NUN WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_samekh_dagesh,
+        "HEBREW LETTER SAMEKH WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_final_pe_dagesh,
+        "HEBREW LETTER FINAL PE WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_pe_dagesh,
+        "HEBREW LETTER PE WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_tsadi_dagesh,
+        "HEBREW LETTER TSADI WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_qof_dagesh,
+        "HEBREW LETTER QOF WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_resh_dagesh,
+        "HEBREW LETTER RESH WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_shin_dagesh,
+        "HEBREW LETTER SHIN WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_tav_dagesh,
+        "HEBREW LETTER TAV WITH DAGESH",
+    ),
+    (
+        is_apf_consonant_vowel_vav_holam,
+        "HEBREW LETTER VAV WITH HOLAM",
+    ),
+    (
+        is_apf_consonant_vowel_bet_rafe,
+        "HEBREW LETTER BET WITH RAFE",
+    ),
+    (
+        is_apf_consonant_vowel_kaf_rafe,
+        "HEBREW LETTER KAF WITH RAFE",
+    ),
+    (is_apf_consonant_vowel_pe_rafe, "HEBREW LETTER PE WITH RAFE"),
+    (is_apf_ligature_alef_lamed, "HEBREW LIGATURE ALEF LAMED"),
+];
+
+fn all_chars() -> impl Iterator<Item = char> {
+    (0..0x0011_0000).filter_map(char::from_u32)
+}
+
+#[test]
+fn test_script_hebrew() {
+    for c in all_chars() {
+        let hebrew = UCD_SCRIPT_HBR.iter().any(|range| range.contains(&c));
+        assert_eq!(is_script_hbr(c), hebrew, "{:04X}", c as u32);
+        if hebrew {
+            assert!(unicode_name(c).is_some(), "{:04X}", c as u32);
+            assert!(unicode_version(c).unwrap() <= UCD_VERSION);
+        }
+    }
+}
+
+#[test]
+fn test_blocks() {
+    for c in all_chars() {
+        let hbr = UCD_BLOCK_HBR.contains(&c);
+        // only the Hebrew part of the block belongs to the crate
+        let apf = UCD_BLOCK_APF.contains(&c) && c >= '\u{FB1D}';
+        let assigned = unicode_name(c).is_some();
+        assert_eq!(is_hbr_block_range(c), hbr, "{:04X}", c as u32);
+        assert_eq!(
+            is_hbr_block_reserved(c),
+            hbr && !assigned,
+            "{:04X}",
+            c as u32
+        );
+        assert_eq!(is_apf_block_range(c), apf, "{:04X}", c as u32);
+        assert_eq!(
+            is_apf_block_reserved(c),
+            apf && !assigned,
+            "{:04X}",
+            c as u32
+        );
+    }
+}
+
+#[test]
+fn test_leaf_predicates() {
+    assert_eq!(LEAVES.len(), UCD_NAMES.len());
+    for (predicate, name) in LEAVES {
+        let mut matches = all_chars().filter(|c| predicate(*c));
+        let c = matches.next().unwrap();
+        assert_eq!(matches.next(), None, "{}", name);
+        assert_eq!(unicode_name(c), Some(*name), "{:04X}", c as u32);
+    }
+}
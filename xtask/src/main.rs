@@ -0,0 +1,653 @@
+//! Development tasks of the crate, run with `cargo xtask <task>`.
+//!
+//! - `ucd`: generates `src/ucd.rs` from the vendored Unicode Character Database excerpts in
+//!   the `ucd` directory: the script and block ranges, the reserved ranges, the character
+//!   tables of the predicates and the names. Needs `rustfmt`.
+//! - `ucd --check`: fails if `src/ucd.rs` is not up to date with the vendored files.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+const USAGE: &str = "usage: cargo xtask ucd [--check]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+    let result = match args.as_slice() {
+        ["ucd"] => ucd(false),
+        ["ucd", "--check"] => ucd(true),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+fn root() -> PathBuf {
+    // the manifest directory of the xtask is a direct child of the workspace root
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lies in the workspace")
+        .to_path_buf()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+fn ucd(check: bool) -> Result<(), String> {
+    let root = root();
+    let ucd = root.join("ucd");
+    let scripts = read(&ucd.join("Scripts.txt"))?;
+    let blocks = read(&ucd.join("Blocks.txt"))?;
+    let unicode_data = read(&ucd.join("UnicodeData.txt"))?;
+    let generated = generate(&scripts, &blocks, &unicode_data)?;
+
+    let target = root.join("src").join("ucd.rs");
+    if check {
+        if read(&target)? != generated {
+            return Err(format!(
+                "{} is out of date, run `cargo xtask ucd`",
+                target.display()
+            ));
+        }
+    } else {
+        fs::write(&target, generated)
+            .map_err(|error| format!("{}: {}", target.display(), error))?;
+    }
+    Ok(())
+}
+
+// the data lines of a UCD file, without comments
+fn data_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+}
+
+fn parse_code_point(text: &str) -> Result<u32, String> {
+    u32::from_str_radix(text.trim(), 16).map_err(|_| format!("invalid code point: {}", text))
+}
+
+// parses `0590..05FF` or `05BE`
+fn parse_range(text: &str) -> Result<(u32, u32), String> {
+    match text.split_once("..") {
+        Some((first, last)) => Ok((parse_code_point(first)?, parse_code_point(last)?)),
+        None => {
+            let code = parse_code_point(text)?;
+            Ok((code, code))
+        }
+    }
+}
+
+// the version in the first line of a UCD file, e.g. `Scripts-14.0.0.txt`
+fn parse_version(text: &str) -> Result<(u8, u8), String> {
+    let first = text.lines().next().unwrap_or("");
+    let version = first
+        .split('-')
+        .nth(1)
+        .and_then(|rest| rest.strip_suffix(".txt"))
+        .ok_or_else(|| format!("no version in: {}", first))?;
+    let mut parts = version.split('.').map(|part| part.parse::<u8>());
+    match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => Ok((major, minor)),
+        _ => Err(format!("invalid version: {}", version)),
+    }
+}
+
+// the ranges of the given script, merged where adjacent
+fn script_ranges(scripts: &str, script: &str) -> Result<Vec<(u32, u32)>, String> {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for line in data_lines(scripts) {
+        let mut fields = line.split(';').map(str::trim);
+        let range = parse_range(fields.next().unwrap_or(""))?;
+        if fields.next() != Some(script) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.1 + 1 == range.0 => last.1 = range.1,
+            _ => ranges.push(range),
+        }
+    }
+    ranges.sort_unstable();
+    Ok(ranges)
+}
+
+fn block_range(blocks: &str, block: &str) -> Result<(u32, u32), String> {
+    for line in data_lines(blocks) {
+        let mut fields = line.split(';').map(str::trim);
+        let range = parse_range(fields.next().unwrap_or(""))?;
+        if fields.next() == Some(block) {
+            return Ok(range);
+        }
+    }
+    Err(format!("block not found: {}", block))
+}
+
+// a character of UnicodeData.txt
+struct Character<'a> {
+    code: u32,
+    name: &'a str,
+    category: &'a str,
+}
+
+fn parse_characters(unicode_data: &str) -> Result<Vec<Character<'_>>, String> {
+    let mut characters = Vec::new();
+    for line in data_lines(unicode_data) {
+        let mut fields = line.split(';');
+        let code = parse_code_point(fields.next().unwrap_or(""))?;
+        let (name, category) = match (fields.next(), fields.next()) {
+            (Some(name), Some(category)) => (name, category),
+            _ => return Err(format!("no name or category in: {}", line)),
+        };
+        characters.push(Character {
+            code,
+            name,
+            category,
+        });
+    }
+    characters.sort_unstable_by_key(|character| character.code);
+    Ok(characters)
+}
+
+// a table of characters, rendered as `<NAME>_CHARS` and `<NAME>_RANGES`
+struct Table {
+    name: &'static str,
+    // the predicate the table belongs to
+    predicate: &'static str,
+    codes: Vec<u32>,
+    // render the examples of the first table of a group
+    example: bool,
+}
+
+// the Hebrew script, split by block
+struct Script<'a> {
+    hbr: Vec<&'a Character<'a>>,
+    apf: Vec<&'a Character<'a>>,
+}
+
+impl<'a> Script<'a> {
+    fn table<F>(
+        name: &'static str,
+        predicate: &'static str,
+        characters: &[&Character<'_>],
+        filter: F,
+    ) -> Table
+    where
+        F: Fn(&Character<'_>) -> bool,
+    {
+        Table {
+            name,
+            predicate,
+            codes: characters
+                .iter()
+                .filter(|character| filter(character))
+                .map(|character| character.code)
+                .collect(),
+            example: false,
+        }
+    }
+
+    fn hbr<F: Fn(&Character<'_>) -> bool>(
+        &self,
+        name: &'static str,
+        predicate: &'static str,
+        filter: F,
+    ) -> Table {
+        Script::table(name, predicate, &self.hbr, filter)
+    }
+
+    fn apf<F: Fn(&Character<'_>) -> bool>(
+        &self,
+        name: &'static str,
+        predicate: &'static str,
+        filter: F,
+    ) -> Table {
+        Script::table(name, predicate, &self.apf, filter)
+    }
+
+    fn all<F: Fn(&Character<'_>) -> bool>(
+        &self,
+        name: &'static str,
+        predicate: &'static str,
+        filter: F,
+    ) -> Table {
+        let all: Vec<&Character<'_>> = self.hbr.iter().chain(self.apf.iter()).copied().collect();
+        Script::table(name, predicate, &all, filter)
+    }
+}
+
+fn is_letter(character: &Character<'_>) -> bool {
+    character.name.starts_with("HEBREW LETTER ") && character.category == "Lo"
+}
+
+// the consonants without points, i.e. the letters that are not `... WITH ...`
+fn is_consonant(character: &Character<'_>) -> bool {
+    is_letter(character) && !character.name.contains(" WITH ")
+}
+
+// the tables of the character groups, derived from the names of the characters
+fn tables(script: &Script<'_>) -> Vec<Table> {
+    let mut tables = vec![
+        Table {
+            example: true,
+            ..script.all("SCRIPT_HBR", "is_script_hbr", |_| true)
+        },
+        script.all(
+            "SCRIPT_HBR_CONSONANT",
+            "is_script_hbr_consonant",
+            is_consonant,
+        ),
+        script.all("SCRIPT_HBR_POINT", "is_script_hbr_point", |c| {
+            c.name.starts_with("HEBREW POINT ")
+        }),
+        script.all("SCRIPT_HBR_LIGATURE", "is_script_hbr_ligature", |c| {
+            c.name.starts_with("HEBREW LIGATURE ")
+        }),
+        script.all(
+            "SCRIPT_HBR_LIGATURE_YIDDISCH",
+            "is_script_hbr_ligature_yiddisch",
+            |c| c.name.starts_with("HEBREW LIGATURE YIDDISH "),
+        ),
+        Table {
+            example: true,
+            ..script.hbr("HBR_BLOCK", "is_hbr_block", |_| true)
+        },
+        script.hbr("HBR_ACCENT", "is_hbr_accent", |c| {
+            c.name.starts_with("HEBREW ACCENT ")
+        }),
+        script.hbr("HBR_MARK", "is_hbr_mark", |c| {
+            c.name.starts_with("HEBREW MARK ")
+        }),
+        script.hbr("HBR_POINT", "is_hbr_point", |c| {
+            c.name.starts_with("HEBREW POINT ")
+        }),
+        script.hbr("HBR_PUNCTUATION", "is_hbr_punctuation", |c| {
+            c.name.starts_with("HEBREW PUNCTUATION ")
+        }),
+        script.hbr("HBR_CONSONANT", "is_hbr_consonant", is_consonant),
+        script.hbr("HBR_CONSONANT_NORMAL", "is_hbr_consonant_normal", |c| {
+            is_consonant(c) && !c.name.starts_with("HEBREW LETTER FINAL ")
+        }),
+        script.hbr("HBR_CONSONANT_FINAL", "is_hbr_consonant_final", |c| {
+            is_consonant(c) && c.name.starts_with("HEBREW LETTER FINAL ")
+        }),
+        script.hbr("HBR_LIGATURE_YIDDISH", "is_hbr_ligature_yiddish", |c| {
+            c.name.starts_with("HEBREW LIGATURE YIDDISH ")
+        }),
+        Table {
+            example: true,
+            ..script.apf("APF_BLOCK", "is_apf_block", |_| true)
+        },
+        script.apf("APF_CONSONANT", "is_apf_consonant", is_consonant),
+        script.apf(
+            "APF_CONSONANT_WITH_VOWEL",
+            "is_apf_consonant_with_vowel",
+            |c| is_letter(c) && c.name.contains(" WITH "),
+        ),
+        script.apf("APF_POINT_READING_SIGN", "is_apf_point_reading_sign", |c| {
+            c.name.starts_with("HEBREW POINT ")
+        }),
+        script.apf("APF_LIGATURE", "is_apf_ligature", |c| {
+            c.name.starts_with("HEBREW LIGATURE ")
+        }),
+        script.apf("APF_ALTERNATIVE", "is_apf_alternative", |c| {
+            c.name.starts_with("HEBREW LETTER ALTERNATIVE ")
+        }),
+        script.apf("APF_CONSONANT_WIDE", "is_apf_consonant_wide", |c| {
+            c.name.starts_with("HEBREW LETTER WIDE ")
+        }),
+    ];
+    tables.retain(|table| !table.codes.is_empty());
+    tables
+}
+
+// merges the sorted code points into ranges
+fn ranges(codes: &[u32]) -> Vec<(u32, u32)> {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for code in codes {
+        match ranges.last_mut() {
+            Some(last) if last.1 + 1 == *code => last.1 = *code,
+            _ => ranges.push((*code, *code)),
+        }
+    }
+    ranges
+}
+
+// the code points of the range that are not in `assigned`
+fn reserved((first, last): (u32, u32), assigned: &[u32]) -> Vec<u32> {
+    (first..=last)
+        .filter(|code| assigned.binary_search(code).is_err())
+        .collect()
+}
+
+fn char_literal(code: u32) -> String {
+    format!("'\\u{{{:04X}}}'", code)
+}
+
+fn range_literal((first, last): (u32, u32)) -> String {
+    format!("{}..={}", char_literal(first), char_literal(last))
+}
+
+// writes the items of an array one per line, `format` lays them out
+fn write_items(out: &mut String, items: &[String]) -> std::fmt::Result {
+    for item in items {
+        writeln!(out, "        {},", item)?;
+    }
+    Ok(())
+}
+
+// formats the generated source with rustfmt, so that `src/ucd.rs` stays rustfmt-clean
+fn format(source: &str) -> Result<String, String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("rustfmt: {}", error))?;
+    rustfmt
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(source.as_bytes())
+        .map_err(|error| format!("rustfmt: {}", error))?;
+    let output = rustfmt
+        .wait_with_output()
+        .map_err(|error| format!("rustfmt: {}", error))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|error| format!("rustfmt: {}", error))
+}
+
+fn write_table(out: &mut String, table: &Table) -> std::fmt::Result {
+    let (name, predicate) = (table.name, table.predicate);
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The characters for which [`{}`] returns `true`, in code point order.",
+        predicate
+    )?;
+    if table.example {
+        writeln!(out, "    ///")?;
+        writeln!(out, "    /// # Example")?;
+        writeln!(out, "    /// ```")?;
+        writeln!(
+            out,
+            "    /// use hebrew_unicode_script::{{{}, {}_CHARS}};",
+            predicate, name
+        )?;
+        writeln!(out, "    ///")?;
+        writeln!(
+            out,
+            "    /// assert!({}_CHARS.iter().all(|c| {}(*c)));",
+            name, predicate
+        )?;
+        writeln!(out, "    /// ```")?;
+    }
+    writeln!(
+        out,
+        "    pub const {}_CHARS: [char; {}] = [",
+        name,
+        table.codes.len()
+    )?;
+    let chars: Vec<String> = table.codes.iter().map(|code| char_literal(*code)).collect();
+    write_items(out, &chars)?;
+    writeln!(out, "    ];")?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The characters for which [`{}`] returns `true`, as ranges in code point order.",
+        predicate
+    )?;
+    if table.example {
+        writeln!(out, "    ///")?;
+        writeln!(out, "    /// # Example")?;
+        writeln!(out, "    /// ```")?;
+        writeln!(
+            out,
+            "    /// use hebrew_unicode_script::{{{}, {}_RANGES}};",
+            predicate, name
+        )?;
+        writeln!(out, "    ///")?;
+        writeln!(
+            out,
+            "    /// assert!({}_RANGES.iter().all(|range| range.clone().all({})));",
+            name, predicate
+        )?;
+        writeln!(out, "    /// ```")?;
+    }
+    writeln!(
+        out,
+        "    pub const {}_RANGES: &[RangeInclusive<char>] = &[",
+        name
+    )?;
+    write_ranges(out, &ranges(&table.codes))
+}
+
+fn write_ranges(out: &mut String, ranges: &[(u32, u32)]) -> std::fmt::Result {
+    let items: Vec<String> = ranges.iter().map(|range| range_literal(*range)).collect();
+    write_items(out, &items)?;
+    writeln!(out, "    ];")
+}
+
+const FUNCTIONS: &str = r#"
+    // checks if the character lies in one of the sorted ranges
+    pub(crate) const fn in_ranges(c: char, ranges: &[RangeInclusive<char>]) -> bool {
+        let mut index = 0;
+        while index < ranges.len() {
+            let range = &ranges[index];
+            if c < *range.start() {
+                return false;
+            }
+            if c <= *range.end() {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    /// Returns the name of a character of the Hebrew script or of a Hebrew-adjacent
+    /// character, as listed in UnicodeData.txt.
+    ///
+    /// # Example
+    /// ```
+    /// use hebrew_unicode_script::unicode_name;
+    ///
+    /// assert_eq!(unicode_name('א'), Some("HEBREW LETTER ALEF"));
+    /// assert_eq!(unicode_name('₪'), Some("NEW SHEQEL SIGN"));
+    /// assert_eq!(unicode_name('a'), None);
+    /// ```
+    pub fn unicode_name(c: char) -> Option<&'static str> {
+        UCD_NAMES
+            .binary_search_by_key(&c, |(member, _)| *member)
+            .ok()
+            .map(|index| UCD_NAMES[index].1)
+    }
+}
+"#;
+
+fn generate(scripts: &str, blocks: &str, unicode_data: &str) -> Result<String, String> {
+    let version = parse_version(scripts)?;
+    let hebrew = script_ranges(scripts, "Hebrew")?;
+    let hbr_block = block_range(blocks, "Hebrew")?;
+    let apf_block = block_range(blocks, "Alphabetic Presentation Forms")?;
+    let characters = parse_characters(unicode_data)?;
+
+    let in_script = |code: u32| {
+        hebrew
+            .iter()
+            .any(|(first, last)| (*first..=*last).contains(&code))
+    };
+    let in_block = |(first, last): (u32, u32), code: u32| (first..=last).contains(&code);
+    let script = Script {
+        hbr: characters
+            .iter()
+            .filter(|c| in_script(c.code) && in_block(hbr_block, c.code))
+            .collect(),
+        apf: characters
+            .iter()
+            .filter(|c| in_script(c.code) && in_block(apf_block, c.code))
+            .collect(),
+    };
+    // the Hebrew part of the APF block starts at its first Hebrew character
+    let apf_hebrew = match script.apf.first() {
+        Some(first) => (first.code, apf_block.1),
+        None => return Err("no Hebrew characters in the APF block".to_string()),
+    };
+
+    let mut out = String::new();
+    render(
+        &mut out,
+        version,
+        &hebrew,
+        (hbr_block, apf_block, apf_hebrew),
+        &script,
+        &characters,
+    )
+    .expect("writing to a String does not fail");
+    format(&out)
+}
+
+fn render(
+    out: &mut String,
+    (major, minor): (u8, u8),
+    hebrew: &[(u32, u32)],
+    (hbr_block, apf_block, apf_hebrew): ((u32, u32), (u32, u32), (u32, u32)),
+    script: &Script<'_>,
+    characters: &[Character<'_>],
+) -> std::fmt::Result {
+    writeln!(
+        out,
+        "// This file is generated by `cargo xtask ucd` from the files in the `ucd`"
+    )?;
+    writeln!(out, "// directory. Do not edit it by hand.")?;
+    writeln!(out)?;
+    writeln!(out, "pub mod unicode_data {{")?;
+    writeln!(out, "    use crate::*;")?;
+    writeln!(out, "    use core::ops::RangeInclusive;")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The version of the Unicode Character Database the tables are generated from."
+    )?;
+    writeln!(
+        out,
+        "    pub const UCD_VERSION: UnicodeVersion = UnicodeVersion::new({}, {});",
+        major, minor
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The range of the unicode block 'Hebrew' (Blocks.txt)."
+    )?;
+    writeln!(
+        out,
+        "    pub const UCD_BLOCK_HBR: RangeInclusive<char> = {};",
+        range_literal(hbr_block)
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The range of the unicode block 'Alphabetic Presentation Forms' (Blocks.txt)."
+    )?;
+    writeln!(
+        out,
+        "    pub const UCD_BLOCK_APF: RangeInclusive<char> = {};",
+        range_literal(apf_block)
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The Hebrew part of the unicode block 'Alphabetic Presentation Forms', from its"
+    )?;
+    writeln!(
+        out,
+        "    /// first Hebrew character to the end of the block."
+    )?;
+    writeln!(
+        out,
+        "    pub const UCD_BLOCK_APF_HEBREW: RangeInclusive<char> = {};",
+        range_literal(apf_hebrew)
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The characters with the property Script=Hebrew (Scripts.txt), as ranges in code"
+    )?;
+    writeln!(out, "    /// point order.")?;
+    writeln!(
+        out,
+        "    pub const UCD_SCRIPT_HBR: &[RangeInclusive<char>] = &["
+    )?;
+    for range in hebrew {
+        writeln!(out, "        {},", range_literal(*range))?;
+    }
+    writeln!(out, "    ];")?;
+
+    let tables = tables(script);
+    for table in &tables {
+        write_table(out, table)?;
+    }
+
+    // the reserved code points are the unassigned ones of the (Hebrew part of the) blocks
+    let hbr_assigned: Vec<u32> = script.hbr.iter().map(|c| c.code).collect();
+    let apf_assigned: Vec<u32> = script.apf.iter().map(|c| c.code).collect();
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The reserved (unassigned) code points of the unicode block 'Hebrew', as ranges in"
+    )?;
+    writeln!(out, "    /// code point order.")?;
+    writeln!(
+        out,
+        "    pub const HBR_BLOCK_RESERVED_RANGES: &[RangeInclusive<char>] = &["
+    )?;
+    write_ranges(out, &ranges(&reserved(hbr_block, &hbr_assigned)))?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The reserved (unassigned) code points of the Hebrew part of the unicode block"
+    )?;
+    writeln!(
+        out,
+        "    /// 'Alphabetic Presentation Forms', as ranges in code point order."
+    )?;
+    writeln!(
+        out,
+        "    pub const APF_BLOCK_RESERVED_RANGES: &[RangeInclusive<char>] = &["
+    )?;
+    write_ranges(out, &ranges(&reserved(apf_hebrew, &apf_assigned)))?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    /// The names of the characters of UnicodeData.txt (the Hebrew script and the"
+    )?;
+    writeln!(
+        out,
+        "    /// Hebrew-adjacent characters), in code point order."
+    )?;
+    writeln!(out, "    pub const UCD_NAMES: &[(char, &str)] = &[")?;
+    for character in characters {
+        writeln!(
+            out,
+            "        ({}, \"{}\"),",
+            char_literal(character.code),
+            character.name
+        )?;
+    }
+    writeln!(out, "    ];")?;
+    out.push_str(FUNCTIONS);
+    Ok(())
+}